@@ -0,0 +1,23 @@
+#![no_main]
+
+use aoc::fuzzing::day1_parse_line;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    let mut tokens = line.split_whitespace().map(|token| token.parse::<u32>());
+    let expected = match (tokens.next(), tokens.next(), tokens.next()) {
+        (Some(Ok(left)), Some(Ok(right)), None) => Some((left, right)),
+        _ => None,
+    };
+    match day1_parse_line(line) {
+        Ok(pair) => assert_eq!(Some(pair), expected, "accepted malformed line {:?}", line),
+        Err(e) => {
+            assert_eq!(expected, None, "rejected well-formed line {:?}", line);
+            if line.split_whitespace().count() > 2 {
+                assert!(e.token.is_some(), "no offending token for {:?}", line);
+            }
+            // The snippet renderer indexes by column, so exercise it too.
+            let _ = e.on_line(1, line).to_string();
+        }
+    }
+});
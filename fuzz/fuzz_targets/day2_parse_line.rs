@@ -0,0 +1,18 @@
+#![no_main]
+
+use aoc::fuzzing::day2_parse_line;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    let expected: Option<Vec<u32>> = line
+        .split_whitespace()
+        .map(|token| token.parse().ok())
+        .collect();
+    match day2_parse_line(line) {
+        Ok(levels) => assert_eq!(Some(levels), expected, "accepted malformed line {:?}", line),
+        Err(e) => {
+            assert_eq!(expected, None, "rejected well-formed line {:?}", line);
+            let _ = e.on_line(1, line).to_string();
+        }
+    }
+});
@@ -0,0 +1,27 @@
+#![no_main]
+
+use aoc::fuzzing::day3_parse;
+use libfuzzer_sys::fuzz_target;
+
+/// Byte offset just past the first `mul(a,b)` in `text` whose operands parse
+/// to `a` and `b`. Operands may have leading zeros, so compare numbers.
+fn find_mul(text: &str, a: u32, b: u32) -> Option<usize> {
+    text.match_indices("mul(").find_map(|(start, _)| {
+        let args_start = start + "mul(".len();
+        let args_len = text[args_start..].find(')')?;
+        let args = &text[args_start..args_start + args_len];
+        let (x, y) = args.split_once(',')?;
+        let operands = (x.parse::<u32>().ok()?, y.parse::<u32>().ok()?);
+        (operands == (a, b)).then_some(args_start + args_len + 1)
+    })
+}
+
+fuzz_target!(|input: &str| {
+    let mut rest = input;
+    for (a, b) in day3_parse(input) {
+        // Pairs come out in input order, so each must appear after the last.
+        let end = find_mul(rest, a, b)
+            .unwrap_or_else(|| panic!("mul({},{}) is not in the rest of {:?}", a, b, input));
+        rest = &rest[end..];
+    }
+});
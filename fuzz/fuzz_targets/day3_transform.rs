@@ -0,0 +1,23 @@
+#![no_main]
+
+use aoc::fuzzing::day3_transform;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|parse_set: Vec<char>| {
+    if let Some((a, b)) = day3_transform(&parse_set) {
+        let text: String = parse_set.iter().collect();
+        let args = text
+            .strip_prefix("mul(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .expect("accepted a state that is not mul(..)");
+        let (x, y) = args
+            .split_once(',')
+            .expect("accepted a state without a comma");
+        assert_eq!((x.parse(), y.parse()), (Ok(a), Ok(b)), "{:?}", text);
+        assert!(
+            (1..=3).contains(&x.len()) && (1..=3).contains(&y.len()),
+            "{:?}",
+            text
+        );
+    }
+});
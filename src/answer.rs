@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle. Numeric answers compare by value, so
+/// `Integer(11)` equals `Signed(11)`.
+#[derive(Debug, Eq, Clone)]
+#[non_exhaustive]
+pub enum Answer {
+    /// A count or sum, the usual kind of answer.
+    Integer(u64),
+    /// A number that may be negative.
+    Signed(i64),
+    /// A single word or code.
+    Text(String),
+    /// Multi-line output, e.g. letters drawn as an ASCII picture.
+    Lines(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => n.fmt(f),
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Text(s) => f.pad(s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Integer(u64::from(value))
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Lines(value)
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Integer(a), Answer::Integer(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Integer(a), Answer::Signed(b)) | (Answer::Signed(b), Answer::Integer(a)) => {
+                i128::from(*a) == i128::from(*b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Lines(a), Answer::Lines(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<u64> for Answer {
+    fn eq(&self, other: &u64) -> bool {
+        match self {
+            Answer::Integer(n) => n == other,
+            Answer::Signed(n) => u64::try_from(*n).is_ok_and(|n| n == *other),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        let rendered = self.to_string();
+        rendered == *other
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("1,2,3").to_string(), "1,2,3");
+        let picture = vec![String::from("#..#"), String::from("####")];
+        assert_eq!(Answer::from(picture).to_string(), "#..#\n####");
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(Answer::Integer(11), 11);
+        assert_eq!(Answer::Signed(11), 11);
+        assert_ne!(Answer::Signed(-11), 11);
+        assert_eq!(Answer::from(-7i64), "-7");
+    }
+
+    #[test]
+    fn numeric_variants_equal_by_value() {
+        assert_eq!(Answer::Signed(11), Answer::Integer(11));
+        assert_eq!(Answer::Integer(11), Answer::Signed(11));
+        assert_ne!(Answer::Signed(-1), Answer::Integer(u64::MAX));
+        assert_ne!(Answer::Integer(11), Answer::Text(String::from("11")));
+    }
+}
@@ -0,0 +1,160 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path};
+
+use crate::batch::RunReport;
+use crate::config::ConfigError;
+use crate::runner::Part;
+
+/// Known answers keyed by day number and part, loaded from a TOML file like:
+///
+/// ```toml
+/// [day01]
+/// part1 = 11
+/// part2 = 31
+/// ```
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl AnswerStore {
+    /// Reads and parses the answers file at `path`.
+    pub fn load(path: &Path) -> Result<AnswerStore, ConfigError> {
+        let error = |message: String| ConfigError {
+            path: path.to_path_buf(),
+            message,
+        };
+        let contents = fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+        AnswerStore::parse(&contents).map_err(error)
+    }
+
+    /// Parses the TOML contents of an answers file.
+    pub fn parse(contents: &str) -> Result<AnswerStore, String> {
+        let table = contents
+            .parse::<toml::Table>()
+            .map_err(|e| e.message().to_string())?;
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in &table {
+            let day: u8 = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .ok_or(format!("Invalid day key: {}", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("Expected a table for {}", day_key))?;
+            for (part_key, value) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("Invalid part key: {}.{}", day_key, part_key)),
+                };
+                let answer = match value {
+                    toml::Value::String(s) => s.clone(),
+                    value => value.to_string(),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(AnswerStore { answers })
+    }
+
+    /// The known answer for one part of a day, as written in the file.
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|s| s.as_str())
+    }
+}
+
+/// How a run compared with its known answer in `aoc verify`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Verdict {
+    /// The answer matched.
+    Match,
+    /// The answer differed from the known one.
+    Mismatch {
+        /// The known answer.
+        expected: String,
+        /// The answer the run produced.
+        actual: String,
+    },
+    /// The run failed; holds the error message.
+    Failed(String),
+    /// No answer is known for this part.
+    Unknown,
+}
+
+impl Verdict {
+    /// Compares a run with the store.
+    pub fn check(store: &AnswerStore, report: &RunReport) -> Verdict {
+        let Some(expected) = store.get(report.day.number, report.part) else {
+            return Verdict::Unknown;
+        };
+        match &report.result {
+            Ok(answer) if *answer == expected => Verdict::Match,
+            Ok(answer) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                actual: answer.to_string(),
+            },
+            Err(e) => Verdict::Failed(e.to_string()),
+        }
+    }
+
+    /// Whether the verdict should fail `aoc verify`.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Failed(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Match => write!(f, "ok"),
+            Verdict::Unknown => write!(f, "skipped (no known answer)"),
+            Verdict::Failed(e) => write!(f, "FAILED\n{}", e),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH")?;
+                for line in expected.lines() {
+                    write!(f, "\n  - {}", line)?;
+                }
+                for line in actual.lines() {
+                    write!(f, "\n  + {}", line)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let store = AnswerStore::parse(
+            "[day01]\n\
+             part1 = 11\n\
+             part2 = \"31\"\n\
+             [day03]\n\
+             part1 = 161\n",
+        )
+        .unwrap();
+        assert_eq!(store.get(1, Part::One), Some("11"));
+        assert_eq!(store.get(1, Part::Two), Some("31"));
+        assert_eq!(store.get(3, Part::One), Some("161"));
+        assert_eq!(store.get(3, Part::Two), None);
+    }
+
+    #[test]
+    fn parse_invalid_key() {
+        let result = AnswerStore::parse("[day01]\npart3 = 1\n");
+        assert_eq!(result, Err(String::from("Invalid part key: day01.part3")));
+    }
+
+    #[test]
+    fn mismatch_display() {
+        let verdict = Verdict::Mismatch {
+            expected: String::from("11"),
+            actual: String::from("12"),
+        };
+        assert_eq!(verdict.to_string(), "MISMATCH\n  - 11\n  + 12");
+    }
+}
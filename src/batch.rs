@@ -0,0 +1,173 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::BufReader,
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::registry::{days, Day};
+use crate::runner::Part;
+use crate::timing::{record, Timings};
+
+/// The outcome of running one part of one day in a multi-day run.
+#[derive(Debug)]
+pub struct RunReport {
+    /// The day that was run.
+    pub day: &'static Day,
+    /// The part that was run.
+    pub part: Part,
+    /// The answer, or why there is none (a missing input counts).
+    pub result: Result<Answer, Box<dyn Error + Send + Sync>>,
+    /// Time spent solving, empty when the input could not be opened.
+    pub timings: Timings,
+}
+
+impl RunReport {
+    /// Column titles aligned with the `Display` rows.
+    pub fn header() -> String {
+        format!(
+            "{:<6} {:<4} {:<20} {:>12}",
+            "day", "part", "answer", "elapsed"
+        )
+    }
+}
+
+impl Display for RunReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answer = match &self.result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!(
+                "error: {}",
+                e.to_string().lines().next().unwrap_or_default()
+            ),
+        };
+        write!(
+            f,
+            "{:<6} {:<4} {:<20} {:>12}",
+            self.day.name,
+            self.part,
+            answer,
+            format!("{:.2?}", self.timings.total)
+        )
+    }
+}
+
+fn run_part(config: &Config, day: &'static Day, part: Part) -> RunReport {
+    let path = config.input_path(day.number, None);
+    let (result, timings) = match File::open(&path) {
+        Ok(f) => {
+            let (result, timings) = record(|| day.solve(part, BufReader::new(f)));
+            (result.map_err(|e| e.into()), timings)
+        }
+        Err(e) => (
+            Err(format!("{}: {}", path.display(), e).into()),
+            Timings::default(),
+        ),
+    };
+    RunReport {
+        day,
+        part,
+        result,
+        timings,
+    }
+}
+
+/// Runs both parts of every day of the configured year against its default
+/// input, on up to `jobs` threads. Reports come back in day and part order
+/// whatever order the jobs finish in. A failing day is reported in its row and
+/// does not stop the others.
+pub fn run_all(config: &Config, jobs: usize) -> Vec<RunReport> {
+    let tasks: Vec<(&'static Day, Part)> = days()
+        .filter(|day| day.year == config.year)
+        .flat_map(|day| [Part::One, Part::Two].map(|part| (day, part)))
+        .collect();
+    let next = AtomicUsize::new(0);
+    // Each job runs start to finish on one thread, so the thread-local phase
+    // timings `record` collects only ever see that job.
+    let worker = || {
+        let mut done = Vec::new();
+        while let Some(&(day, part)) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+            done.push((day.number, part, run_part(config, day, part)));
+        }
+        done
+    };
+    let mut reports: Vec<(u8, Part, RunReport)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, tasks.len().max(1)))
+            .map(|_| scope.spawn(worker))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    reports.sort_by_key(|(day, part, _)| (*day, *part));
+    reports.into_iter().map(|(_, _, report)| report).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn reports_cross_threads() {
+        assert_send_sync::<Day>();
+        assert_send_sync::<Config>();
+        fn assert_send<T: Send>() {}
+        assert_send::<RunReport>();
+    }
+
+    #[test]
+    fn parallel_runs_keep_order() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("day01.txt"),
+            "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+        )
+        .unwrap();
+        fs::write(dir.join("day02.txt"), "7 6 4 2 1\n1 3 2 4 5\n").unwrap();
+        let config = Config {
+            input_dir: dir.clone(),
+            ..Config::default()
+        };
+
+        let sequential = run_all(&config, 1);
+        let parallel = run_all(&config, 4);
+        let rows = |reports: &[RunReport]| -> Vec<(u8, Part, Option<String>)> {
+            reports
+                .iter()
+                .map(|r| {
+                    (
+                        r.day.number,
+                        r.part,
+                        r.result.as_ref().ok().map(|a| a.to_string()),
+                    )
+                })
+                .collect()
+        };
+        assert_eq!(rows(&parallel), rows(&sequential));
+        assert_eq!(
+            parallel.len(),
+            days().filter(|day| day.year == 2024).count() * 2
+        );
+        assert_eq!(
+            rows(&parallel)[..4],
+            [
+                (1, Part::One, Some(String::from("11"))),
+                (1, Part::Two, Some(String::from("31"))),
+                (2, Part::One, Some(String::from("1"))),
+                (2, Part::Two, Some(String::from("2"))),
+            ]
+        );
+        assert!(parallel[0].timings.phases.iter().any(|p| p.name == "parse"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::config::ConfigError;
+use crate::registry::Variant;
+use crate::runner::{input_str, InputParseError, Part};
+
+/// Summary of the timed samples of one benchmark.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct BenchStats {
+    /// Number of timed runs.
+    pub samples: usize,
+    /// Mean run time, compared against the baseline.
+    pub mean: Duration,
+    /// Median run time.
+    pub median: Duration,
+    /// Fastest run.
+    pub min: Duration,
+    /// Standard deviation of the run times.
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Summarizes run times; all zero for no samples.
+    pub fn from_samples(samples: &[Duration]) -> BenchStats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len().max(1) as f64;
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let median = match sorted.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+            len => sorted[len / 2],
+        };
+        BenchStats {
+            samples: sorted.len(),
+            mean: Duration::from_secs_f64(mean),
+            median,
+            min: sorted.first().copied().unwrap_or_default(),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {:.2?}, median {:.2?}, min {:.2?}, stddev {:.2?} ({} samples)",
+            self.mean, self.median, self.min, self.stddev, self.samples
+        )
+    }
+}
+
+/// Times `samples` runs of one part of a day's variant after `warmup` untimed
+/// runs. The input is already in memory, so only the `Run` implementation is
+/// measured. The command line requires at least one sample.
+pub fn bench(
+    variant: &Variant,
+    part: Part,
+    input: &[u8],
+    warmup: usize,
+    samples: usize,
+) -> Result<BenchStats, InputParseError> {
+    let input = input_str(input)?;
+    for _ in 0..warmup {
+        black_box(variant.solve_str(part, black_box(input))?);
+    }
+    let timings = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            let answer = variant.solve_str(part, black_box(input));
+            let elapsed = start.elapsed();
+            black_box(answer).map(|_| elapsed)
+        })
+        .collect::<Result<Vec<Duration>, InputParseError>>()?;
+    Ok(BenchStats::from_samples(&timings))
+}
+
+/// Mean run times from earlier benchmarks, stored as `[dayNN] partN = <nanoseconds>`
+/// for the default variant and `[dayNN.<variant>] partN = <nanoseconds>` for others.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Baseline {
+    path: PathBuf,
+    means: BTreeMap<(u8, String, Part), Duration>,
+}
+
+fn read_parts(
+    means: &mut BTreeMap<(u8, String, Part), Duration>,
+    day: u8,
+    variant: &str,
+    parts: &toml::Value,
+) {
+    for (part, key) in [(Part::One, "part1"), (Part::Two, "part2")] {
+        // A zero mean would make every later run look infinitely slower.
+        if let Some(nanos) = parts
+            .get(key)
+            .and_then(|v| v.as_integer())
+            .filter(|&nanos| nanos > 0)
+        {
+            means.insert(
+                (day, variant.to_string(), part),
+                Duration::from_nanos(nanos as u64),
+            );
+        }
+    }
+}
+
+impl Baseline {
+    /// Reads the baseline file at `path`; a missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Baseline, ConfigError> {
+        let error = |message: String| ConfigError {
+            path: path.to_path_buf(),
+            message,
+        };
+        let mut means = BTreeMap::new();
+        let table = match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse::<toml::Table>()
+                .map_err(|e| error(e.message().to_string()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => toml::Table::new(),
+            Err(e) => return Err(error(e.to_string())),
+        };
+        for (day_key, parts) in &table {
+            let day: u8 = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .ok_or(error(format!("Invalid day key: {}", day_key)))?;
+            read_parts(&mut means, day, "default", parts);
+            let variants = parts.as_table().into_iter().flatten();
+            for (variant, parts) in variants.filter(|(_, v)| v.is_table()) {
+                read_parts(&mut means, day, variant, parts);
+            }
+        }
+        Ok(Baseline {
+            path: path.to_path_buf(),
+            means,
+        })
+    }
+
+    /// The saved mean for one variant of a day and part.
+    pub fn get(&self, day: u8, variant: &str, part: Part) -> Option<Duration> {
+        self.means.get(&(day, variant.to_string(), part)).copied()
+    }
+
+    /// Records a new mean and writes the whole baseline back.
+    pub fn save(
+        &mut self,
+        day: u8,
+        variant: &str,
+        part: Part,
+        mean: Duration,
+    ) -> Result<(), ConfigError> {
+        self.means.insert((day, variant.to_string(), part), mean);
+        let mut table = toml::Table::new();
+        for ((day, variant, part), mean) in &self.means {
+            let mut entry = table
+                .entry(format!("day{:02}", day))
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if variant != "default" {
+                if let toml::Value::Table(variants) = entry {
+                    entry = variants
+                        .entry(variant.clone())
+                        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                }
+            }
+            if let toml::Value::Table(parts) = entry {
+                parts.insert(format!("part{}", part), (mean.as_nanos() as i64).into());
+            }
+        }
+        let error = |e: std::io::Error| ConfigError {
+            path: self.path.clone(),
+            message: e.to_string(),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+        fs::write(&self.path, table.to_string()).map_err(error)
+    }
+}
+
+/// Relative slowdown of `current` over `baseline` when it exceeds `threshold`
+/// (e.g. 0.1 for 10%).
+pub fn regression(current: Duration, baseline: Duration, threshold: f64) -> Option<f64> {
+    let change = current.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0;
+    (change > threshold).then_some(change)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples: Vec<Duration> = [4, 2, 8, 6].map(Duration::from_micros).to_vec();
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.stddev.as_nanos(), 2236);
+    }
+
+    #[test]
+    fn detects_regression() {
+        let baseline = Duration::from_millis(10);
+        assert_eq!(regression(Duration::from_millis(10), baseline, 0.1), None);
+        assert_eq!(regression(Duration::from_micros(10500), baseline, 0.1), None);
+        assert!(regression(Duration::from_millis(12), baseline, 0.1).is_some());
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.toml", std::process::id()));
+        let mut baseline = Baseline::load(&path).unwrap();
+        assert_eq!(baseline.get(1, "default", Part::One), None);
+        baseline
+            .save(1, "default", Part::One, Duration::from_micros(150))
+            .unwrap();
+        baseline
+            .save(1, "default", Part::Two, Duration::from_micros(300))
+            .unwrap();
+        baseline
+            .save(2, "linear", Part::One, Duration::from_micros(90))
+            .unwrap();
+
+        let reloaded = Baseline::load(&path).unwrap();
+        assert_eq!(reloaded, baseline);
+        assert_eq!(
+            reloaded.get(1, "default", Part::Two),
+            Some(Duration::from_micros(300))
+        );
+        assert_eq!(
+            reloaded.get(2, "linear", Part::One),
+            Some(Duration::from_micros(90))
+        );
+        assert_eq!(reloaded.get(2, "default", Part::One), None);
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("[day02.linear]"));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn bench_day() {
+        let day = crate::registry::find(2024, "day2").unwrap();
+        let linear = day.variant(Some("linear")).unwrap();
+        let stats = bench(linear, Part::One, b"7 6 4 2 1\n1 3 2 4 5\n", 1, 5).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert!(stats.min <= stats.median);
+    }
+
+    #[test]
+    fn reject_zero_samples() {
+        use clap::Parser;
+
+        let args = ["aoc", "bench", "day1", "--samples", "0"];
+        assert!(crate::cli::Cli::try_parse_from(args).is_err());
+
+        let path = std::env::temp_dir().join(format!("aoc-bench-zero-{}.toml", std::process::id()));
+        fs::write(&path, "[day01]\npart1 = 0\npart2 = 300\n").unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        assert_eq!(baseline.get(1, "default", Part::One), None);
+        assert_eq!(
+            baseline.get(1, "default", Part::Two),
+            Some(Duration::from_nanos(300))
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+//! The command line of the `aoc` binary. Public so the binary can use it, but
+//! not part of the stable library API.
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+pub use crate::answers::{AnswerStore, Verdict};
+pub use crate::batch::{run_all, RunReport};
+pub use crate::bench::{bench, regression, Baseline, BenchStats};
+pub use crate::client::{Client, ClientError, FetchOutcome};
+pub use crate::config::{Config, ConfigError};
+pub use crate::fixtures::{
+    extract as extract_examples, load as load_fixtures, write as write_fixtures, Example,
+};
+pub use crate::generate::{generate, GenerateOptions, Generator, NoGeneratorError, Rng};
+pub use crate::output::{render_run, render_run_at, render_runs, watch_header, Format};
+pub use crate::registry::DayCommand;
+pub use crate::scaffold::{new_day, ScaffoldError, Template};
+pub use crate::submit::{unix_now, Attempt, Bounds, Rejection, SubmissionLog, SubmitOutcome};
+pub use crate::timing::{phase, record, Phase, Timings};
+pub use crate::watch::{clock, Watcher};
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+
+    /// Event year; defaults to `year` in `aoc.toml`, then 2024
+    #[arg(short, long, global=true)]
+    pub year: Option<u16>,
+
+    /// Use the named example input `<fixtures_dir>/dayNN.exampleN.txt`
+    #[arg(short, long, global=true)]
+    pub example: Option<u32>,
+
+    #[arg(short, long, global=true, default_value_t=false)]
+    pub part2: bool,
+
+    /// Print a parse/solve timing breakdown next to the answer
+    #[arg(short, long, global=true, default_value_t=false)]
+    pub time: bool,
+
+    /// Keep running and re-solve whenever the input file changes
+    #[arg(short, long, global=true, default_value_t=false)]
+    pub watch: bool,
+
+    /// Threads for multi-day runs; defaults to the available parallelism
+    #[arg(short, long, global=true)]
+    pub jobs: Option<usize>,
+
+    /// Run the named implementation of the day instead of `default`
+    #[arg(long, global=true)]
+    pub variant: Option<String>,
+
+    /// Run every implementation of the day and fail if their answers differ
+    #[arg(long, global=true, default_value_t=false, conflicts_with="variant")]
+    pub cross_check: bool,
+
+    /// Print results as plain text, JSON or CSV
+    #[arg(long, value_enum, global=true, default_value_t)]
+    pub format: Format,
+}
+
+/// The input positional of commands that solve a day. It belongs to those
+/// subcommands rather than to `Cli`, so a leading `aoc 2023 …` can only be
+/// read as a year and not as an input path.
+#[derive(Args, Debug, Default, Eq, PartialEq, Clone)]
+pub struct InputArg {
+    /// Path to the puzzle input; `-` reads stdin, no path uses `<input_dir>/dayNN.txt`
+    pub input: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// List the registered days of the selected year
+    List,
+    /// Run both parts of every registered day of the selected year against its default input
+    All,
+    /// Check every registered day against the known answers file
+    Verify,
+    /// Download a day's puzzle input into the input directory
+    Fetch {
+        day: u8,
+    },
+    /// Solve a day and submit the answer for the selected part
+    Submit {
+        day: String,
+
+        #[command(flatten)]
+        input: InputArg,
+    },
+    /// Solve a day and check the answer against previously rejected submissions
+    Check {
+        day: String,
+
+        #[command(flatten)]
+        input: InputArg,
+    },
+    /// Create, register and add empty inputs for a new day module
+    New {
+        day: u8,
+
+        #[arg(long, value_enum, default_value_t)]
+        template: Template,
+    },
+    /// Benchmark one part of a day and compare it against the saved baseline
+    Bench {
+        day: String,
+
+        #[command(flatten)]
+        input: InputArg,
+
+        /// Untimed runs before sampling
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs to collect
+        #[arg(
+            long,
+            default_value_t = 20,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        samples: usize,
+
+        /// Overwrite the saved baseline with this run
+        #[arg(long, default_value_t = false)]
+        save_baseline: bool,
+
+        /// Slowdown over the baseline, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Print a random puzzle input for a day, for stress tests and benchmarks
+    Generate {
+        day: String,
+
+        /// Seed for the generator; a fresh one is picked and reported when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Number of lines to generate instead of the day's default
+        #[arg(long)]
+        lines: Option<usize>,
+
+        /// Share of day2 reports that are safe
+        #[arg(long, default_value_t = 0.5)]
+        safe_ratio: f64,
+
+        /// Share of day3 chunks that are valid `mul(x,y)` instructions
+        #[arg(long, default_value_t = 0.1)]
+        valid_density: f64,
+
+        /// Share of day3 chunks that are near-misses of `mul(x,y)`
+        #[arg(long, default_value_t = 0.1)]
+        near_miss_density: f64,
+    },
+    /// Extract example inputs and answers from a saved puzzle page into fixtures
+    Extract {
+        day: u8,
+        page: PathBuf,
+    },
+    #[command(flatten)]
+    Day(DayCommand),
+}
@@ -0,0 +1,264 @@
+use std::{error::Error, fmt::Display, fs, path::Path};
+
+use ureq::Agent;
+
+use crate::config::Config;
+use crate::runner::Part;
+use crate::submit::SubmitOutcome;
+
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/kmlanni91/aoc2024)"
+);
+
+/// A request that failed, or a response the site refused.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ClientError {
+    /// What went wrong, including the URL and status for error responses.
+    pub message: String,
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(e: std::io::Error) -> Self {
+        ClientError {
+            message: e.to_string(),
+        }
+    }
+}
+
+/// Whether `fetch_input_to` had to download the input.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FetchOutcome {
+    /// The input was downloaded and written.
+    Downloaded,
+    /// A non-empty input was already on disk.
+    Cached,
+}
+
+/// Talks to the puzzle site at `base_url`, authenticated with the session cookie.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// A client for the site at `base_url`.
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// A client for the configured site, failing without a session token.
+    pub fn from_config(config: &Config) -> Result<Client, ClientError> {
+        let session = config.session.as_deref().ok_or(ClientError {
+            message: String::from("No session token; set AOC_SESSION or `session` in aoc.toml"),
+        })?;
+        Ok(Client::new(&config.base_url, session))
+    }
+
+    /// Downloads a day's puzzle input.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError {
+                message: format!("GET {} returned {}", url, status),
+            });
+        }
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    /// Posts an answer and reports how the site judged it.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<SubmitOutcome, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.number().to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError {
+                message: format!("POST {} returned {}", url, status),
+            });
+        }
+        Ok(SubmitOutcome::parse(&response.body_mut().read_to_string()?))
+    }
+
+    /// Downloads the input to `path` unless it is already there. An empty file
+    /// does not count as cached.
+    pub fn fetch_input_to(
+        &self,
+        year: u16,
+        day: u8,
+        path: &Path,
+    ) -> Result<FetchOutcome, ClientError> {
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(FetchOutcome::Cached);
+        }
+        let input = self.fetch_input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, input)?;
+        Ok(FetchOutcome::Downloaded)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves one canned response per entry on a local port and returns the
+    /// raw requests it received.
+    pub(crate) fn stub_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                            content_length = len.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut body_buf = vec![0; content_length];
+                    reader.read_exact(&mut body_buf).unwrap();
+                    request.push_str(&String::from_utf8(body_buf).unwrap());
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetch_sends_session_and_user_agent() {
+        let (base_url, server) = stub_server(vec![(200, "3   4\n4   3\n")]);
+        let client = Client::new(&base_url, "abc123");
+
+        let input = client.fetch_input(2024, 1).unwrap();
+        assert_eq!(input, "3   4\n4   3\n");
+
+        let requests = server.join().unwrap();
+        let request = requests[0].to_lowercase();
+        assert!(request.starts_with("get /2024/day/1/input "));
+        assert!(request.contains("cookie: session=abc123"));
+        assert!(request.contains("user-agent: aoc/"));
+    }
+
+    #[test]
+    fn fetch_error_status() {
+        let (base_url, server) = stub_server(vec![(404, "Not found")]);
+        let client = Client::new(&base_url, "abc123");
+
+        let error = client.fetch_input(2024, 25).unwrap_err();
+        assert!(
+            error.message.ends_with("returned 404 Not Found"),
+            "{}",
+            error
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submit_posts_answer() {
+        let (base_url, server) = stub_server(vec![(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        )]);
+        let client = Client::new(&base_url, "abc123");
+
+        let outcome = client.submit(2024, 1, Part::Two, "31").unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/1/answer "));
+        assert!(requests[0].ends_with("level=2&answer=31"));
+    }
+
+    #[test]
+    fn fetch_to_uses_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("day01.txt");
+        let (base_url, server) = stub_server(vec![(200, "1 2\n")]);
+        let client = Client::new(&base_url, "abc123");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(
+            client.fetch_input_to(2024, 1, &path).unwrap(),
+            FetchOutcome::Downloaded
+        );
+        // The stub only answers once, so a second request would fail.
+        assert_eq!(
+            client.fetch_input_to(2024, 1, &path).unwrap(),
+            FetchOutcome::Cached
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n");
+        server.join().unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
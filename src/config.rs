@@ -0,0 +1,158 @@
+use std::{env, error::Error, fmt::Display, fs, path::PathBuf};
+
+const CONFIG_FILE: &str = "aoc.toml";
+const DEFAULT_YEAR: u16 = 2024;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings read from `aoc.toml` (or the file named by `AOC_CONFIG`),
+/// overridden by `AOC_*` environment variables.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Config {
+    /// The event whose days are run; defaults to 2024.
+    pub year: u16,
+    /// Directory holding `dayNN.txt` inputs; defaults to `inputs/<year>`.
+    pub input_dir: PathBuf,
+    /// Known answers checked by `aoc verify`; defaults to `<input_dir>/answers.toml`.
+    pub answers_file: PathBuf,
+    /// Log of every submitted answer; defaults to `<input_dir>/submissions.toml`.
+    pub submissions_file: PathBuf,
+    /// Baseline mean run times saved by `aoc bench`; defaults to `<input_dir>/bench.toml`.
+    pub bench_file: PathBuf,
+    /// Example inputs and answers extracted from puzzle pages; defaults to `fixtures/<year>`.
+    pub fixtures_dir: PathBuf,
+    /// Value of the `session` cookie used to download inputs.
+    pub session: Option<String>,
+    /// Site the client talks to; defaults to `https://adventofcode.com`.
+    pub base_url: String,
+}
+
+/// A settings or data file that could not be read or parsed.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ConfigError {
+    /// The file at fault.
+    pub path: PathBuf,
+    /// What was wrong with it.
+    pub message: String,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl Error for ConfigError {}
+
+impl Default for Config {
+    fn default() -> Self {
+        let input_dir = PathBuf::from("inputs").join(DEFAULT_YEAR.to_string());
+        Config {
+            year: DEFAULT_YEAR,
+            answers_file: input_dir.join("answers.toml"),
+            submissions_file: input_dir.join("submissions.toml"),
+            bench_file: input_dir.join("bench.toml"),
+            input_dir,
+            fixtures_dir: PathBuf::from("fixtures").join(DEFAULT_YEAR.to_string()),
+            session: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+        }
+    }
+}
+
+impl Config {
+    /// Reads the settings with the configured year.
+    pub fn load() -> Result<Config, ConfigError> {
+        Config::load_for_year(None)
+    }
+
+    /// Like `load`, but `year` (e.g. from the command line) wins over the
+    /// configured one, and the default directories follow it.
+    pub fn load_for_year(year: Option<u16>) -> Result<Config, ConfigError> {
+        let path = env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(CONFIG_FILE));
+        let table = match fs::read_to_string(&path) {
+            Ok(contents) => contents.parse::<toml::Table>().map_err(|e| ConfigError {
+                path: path.clone(),
+                message: e.message().to_string(),
+            })?,
+            Err(_) if env::var_os("AOC_CONFIG").is_none() => toml::Table::new(),
+            Err(e) => {
+                return Err(ConfigError {
+                    path,
+                    message: e.to_string(),
+                })
+            }
+        };
+        let lookup = |key: &str| {
+            env::var(format!("AOC_{}", key.to_uppercase()))
+                .ok()
+                .or_else(|| match table.get(key)? {
+                    toml::Value::String(s) => Some(s.clone()),
+                    value => Some(value.to_string()),
+                })
+        };
+
+        let year = match (year, lookup("year")) {
+            (Some(year), _) => year,
+            (None, Some(year)) => year.parse().map_err(|_e| ConfigError {
+                path: path.clone(),
+                message: format!("Invalid year: {}", year),
+            })?,
+            (None, None) => DEFAULT_YEAR,
+        };
+        let input_dir = lookup("input_dir")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("inputs").join(year.to_string()));
+        let answers_file = lookup("answers_file")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| input_dir.join("answers.toml"));
+        let submissions_file = lookup("submissions_file")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| input_dir.join("submissions.toml"));
+        let bench_file = lookup("bench_file")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| input_dir.join("bench.toml"));
+        let fixtures_dir = lookup("fixtures_dir")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("fixtures").join(year.to_string()));
+        let base_url = lookup("base_url").unwrap_or_else(|| String::from(DEFAULT_BASE_URL));
+        Ok(Config {
+            year,
+            input_dir,
+            answers_file,
+            submissions_file,
+            bench_file,
+            fixtures_dir,
+            session: lookup("session"),
+            base_url,
+        })
+    }
+
+    /// Resolves `<input_dir>/dayNN.txt`, or for a named example input the
+    /// `<fixtures_dir>/dayNN.exampleN.txt` that `aoc extract` writes.
+    pub fn input_path(&self, day: u8, example: Option<u32>) -> PathBuf {
+        match example {
+            Some(n) => crate::fixtures::example_path(&self.fixtures_dir, day, n as usize),
+            None => self.input_dir.join(format!("day{:02}.txt", day)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn input_path() {
+        let config = Config::default();
+        assert_eq!(
+            config.input_path(3, None),
+            PathBuf::from("inputs/2024/day03.txt")
+        );
+        assert_eq!(
+            config.input_path(3, Some(2)),
+            PathBuf::from("fixtures/2024/day03.example2.txt")
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::runner::Part;
+
+/// An example input from a puzzle description, with the answers it is known to produce.
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct Example {
+    /// The input as shown on the page.
+    pub input: String,
+    /// The answer to part one, if the page gives one for this example.
+    pub part1: Option<String>,
+    /// The answer to part two, if the page gives one for this example.
+    pub part2: Option<String>,
+}
+
+impl Example {
+    /// The known answer for a part.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Pulls the `<pre><code>` example blocks and highlighted answers out of a
+/// saved puzzle page. Each part's answer is the last `<code><em>` in its
+/// `<article>` and is paired with the longest example shown in or before it.
+pub fn extract(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    let mut current: Option<usize> = None;
+    for (part, article) in sections(html, "<article", "</article>").take(2).enumerate() {
+        let longest = sections(article, "<pre><code>", "</code></pre>")
+            .map(|block| decode(&strip_tags(block)))
+            .fold(None, |longest: Option<String>, block| match longest {
+                Some(l) if l.len() >= block.len() => Some(l),
+                _ => Some(block),
+            });
+        if let Some(block) = longest {
+            let existing = examples.iter().position(|example| example.input == block);
+            current = Some(existing.unwrap_or_else(|| {
+                examples.push(Example {
+                    input: block,
+                    ..Example::default()
+                });
+                examples.len() - 1
+            }));
+        }
+        let answer = sections(article, "<code><em>", "</em></code>")
+            .last()
+            .map(|answer| decode(&strip_tags(answer)));
+        if let (Some(idx), Some(answer)) = (current, answer) {
+            match part {
+                0 => examples[idx].part1 = Some(answer),
+                _ => examples[idx].part2 = Some(answer),
+            }
+        }
+    }
+    examples
+}
+
+fn sections<'a>(
+    html: &'a str,
+    open: &'static str,
+    close: &'static str,
+) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)?;
+        let after_open = &rest[start + open.len()..];
+        let body_start = match open.ends_with('>') {
+            true => 0,
+            false => after_open.find('>')? + 1,
+        };
+        let body = &after_open[body_start..];
+        let end = body.find(close)?;
+        rest = &body[end + close.len()..];
+        Some(&body[..end])
+    })
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => (),
+        }
+    }
+    text
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn answers_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.answers.toml", day))
+}
+
+pub(crate) fn example_path(dir: &Path, day: u8, n: usize) -> PathBuf {
+    dir.join(format!("day{:02}.example{}.txt", day, n))
+}
+
+/// Writes `dayNN.exampleN.txt` files and a `dayNN.answers.toml` keyed by example.
+/// Example files left over from an earlier write with more examples are removed.
+pub fn write(dir: &Path, day: u8, examples: &[Example]) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut answers = toml::Table::new();
+    let mut written = Vec::new();
+    for (idx, example) in examples.iter().enumerate() {
+        let path = example_path(dir, day, idx + 1);
+        fs::write(&path, &example.input)?;
+        written.push(path);
+        let mut parts = toml::Table::new();
+        for part in [Part::One, Part::Two] {
+            if let Some(answer) = example.answer(part) {
+                parts.insert(format!("part{}", part), answer.into());
+            }
+        }
+        answers.insert(format!("example{}", idx + 1), toml::Value::Table(parts));
+    }
+    for path in (examples.len() + 1..)
+        .map(|n| example_path(dir, day, n))
+        .take_while(|path| path.exists())
+    {
+        fs::remove_file(path)?;
+    }
+    let path = answers_path(dir, day);
+    fs::write(&path, answers.to_string())?;
+    written.push(path);
+    Ok(written)
+}
+
+/// Reads back the examples `write` produced for a day.
+pub fn load(dir: &Path, day: u8) -> io::Result<Vec<Example>> {
+    let answers = match fs::read_to_string(answers_path(dir, day)) {
+        Ok(contents) => contents
+            .parse::<toml::Table>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.message().to_string()))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    (1..)
+        .map(|n| (n, example_path(dir, day, n)))
+        .take_while(|(_, path)| path.exists())
+        .map(|(n, path)| {
+            let parts = answers.get(&format!("example{}", n));
+            let answer = |key: &str| {
+                parts.and_then(|p| p.get(key)).map(|v| match v {
+                    toml::Value::String(s) => s.clone(),
+                    v => v.to_string(),
+                })
+            };
+            Ok(Example {
+                input: fs::read_to_string(path)?,
+                part1: answer("part1"),
+                part2: answer("part2"),
+            })
+        })
+        .collect()
+}
+
+/// Runs a day against every fixture written for it, for use from `dayN::test`.
+#[cfg(test)]
+pub fn check_examples(year: u16, day: u8, runner: &dyn crate::runner::RunStr) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(year.to_string());
+    let examples = load(&dir, day).expect("Failed to load fixtures");
+    assert!(!examples.is_empty(), "No fixtures for day {}", day);
+    for (idx, example) in examples.iter().enumerate() {
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = example.answer(part) else {
+                continue;
+            };
+            let result = match part {
+                Part::One => runner.run(&example.input),
+                Part::Two => runner.run2(&example.input),
+            }
+            .expect("Unexpected parse error");
+            assert_eq!(
+                result.to_string(),
+                expected,
+                "{} day {} example {} part {}",
+                year,
+                day,
+                idx + 1,
+                part
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = "<main>\n\
+        <article class=\"day-desc\"><h2>--- Day 1: Example ---</h2>\
+        <p>For example:</p>\n<pre><code>3   4\n4   3\n</code></pre>\n\
+        <p>Small: <code>a &lt; b</code></p>\
+        <p>In total, <code><em>3 &gt; 2</em></code> or <code><em>11</em></code>.</p></article>\n\
+        <p>Your puzzle answer was <code>1234</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+        <p>So, the similarity score is <code><em>31</em></code>.</p></article>\n\
+        </main>";
+
+    #[test]
+    fn extract_examples() {
+        let examples = extract(PAGE);
+        assert_eq!(
+            examples,
+            vec![Example {
+                input: String::from("3   4\n4   3\n"),
+                part1: Some(String::from("11")),
+                part2: Some(String::from("31")),
+            }]
+        );
+    }
+
+    #[test]
+    fn write_and_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        let examples = extract(PAGE);
+        write(&dir, 1, &examples).unwrap();
+        assert_eq!(load(&dir, 1).unwrap(), examples);
+        assert_eq!(load(&dir, 2).unwrap(), Vec::new());
+
+        let mut more = examples.clone();
+        more.push(Example {
+            input: String::from("stale"),
+            ..Example::default()
+        });
+        write(&dir, 1, &more).unwrap();
+        assert!(example_path(&dir, 1, more.len()).exists());
+        write(&dir, 1, &examples).unwrap();
+        assert!(!example_path(&dir, 1, more.len()).exists());
+        assert_eq!(load(&dir, 1).unwrap(), examples);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
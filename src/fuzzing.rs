@@ -0,0 +1,20 @@
+//! Parser entry points for the cargo-fuzz targets in `fuzz/`. Only built with
+//! the `fuzzing` feature, so the parsers stay private to their days otherwise.
+
+use crate::runner::InputParseError;
+
+pub fn day1_parse_line(line: &str) -> Result<(u32, u32), InputParseError> {
+    crate::y2024::day1::parse_line(line)
+}
+
+pub fn day2_parse_line(line: &str) -> Result<Vec<u32>, InputParseError> {
+    crate::y2024::day2::parse_line(line)
+}
+
+pub fn day3_parse(input: &str) -> Vec<(u32, u32)> {
+    crate::y2024::day3::Parser::new(input).parse()
+}
+
+pub fn day3_transform(parse_set: &[char]) -> Option<(u32, u32)> {
+    crate::y2024::day3::Parser::transform(parse_set)
+}
@@ -0,0 +1,187 @@
+use std::{error::Error, fmt::Display};
+
+use crate::registry::Day;
+
+/// SplitMix64: tiny, fast and fully determined by its seed, which is all
+/// input generation needs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A generator that always produces the same values for the same seed.
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    /// The next value of the splitmix64 sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform value in `low..=high`.
+    pub fn between(&mut self, low: u32, high: u32) -> u32 {
+        let span = u64::from(high - low) + 1;
+        low + (self.next_u64() % span) as u32
+    }
+
+    /// A uniform value in `[0, 1)`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.unit() < p
+    }
+
+    /// A uniformly chosen item; `items` must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.between(0, items.len() as u32 - 1) as usize]
+    }
+}
+
+/// Knobs shared by every generator. Each day reads the ones that apply to its
+/// format and ignores the rest.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GenerateOptions {
+    /// Number of input lines, or the day's own default
+    pub lines: Option<usize>,
+    /// Share of day2 reports that are safe without the dampener
+    pub safe_ratio: f64,
+    /// Share of day3 chunks that are valid `mul(x,y)` instructions
+    pub valid_density: f64,
+    /// Share of day3 chunks that almost, but not quite, parse as `mul(x,y)`
+    pub near_miss_density: f64,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            lines: None,
+            safe_ratio: 0.5,
+            valid_density: 0.1,
+            near_miss_density: 0.1,
+        }
+    }
+}
+
+/// Registered per day with `with generate` in `days!`.
+pub type Generator = fn(&mut Rng, &GenerateOptions) -> String;
+
+/// `aoc generate` was asked for a day registered without `with generate`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct NoGeneratorError {
+    /// The day's year.
+    pub year: u16,
+    /// The day's name.
+    pub day: &'static str,
+}
+
+impl Display for NoGeneratorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No input generator for {} {}", self.year, self.day)
+    }
+}
+
+impl Error for NoGeneratorError {}
+
+/// Generates a random puzzle input for `day`. The same seed and options always
+/// produce the same input.
+pub fn generate(
+    day: &Day,
+    seed: u64,
+    options: &GenerateOptions,
+) -> Result<String, NoGeneratorError> {
+    let generator = day.generator.ok_or(NoGeneratorError {
+        year: day.year,
+        day: day.name,
+    })?;
+    Ok(generator(&mut Rng::new(seed), options))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::{days, find};
+    use crate::runner::Part;
+
+    #[test]
+    fn rng_is_seeded() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let first: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(first, (0..4).map(|_| c.next_u64()).collect::<Vec<_>>());
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&a.between(3, 5)));
+            assert!((0.0..1.0).contains(&a.unit()));
+        }
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        let options = GenerateOptions {
+            lines: Some(50),
+            ..GenerateOptions::default()
+        };
+        for day in days().filter(|day| day.generator.is_some()) {
+            let input = generate(day, 7, &options).unwrap();
+            assert_eq!(input, generate(day, 7, &options).unwrap());
+            assert_ne!(input, generate(day, 8, &options).unwrap());
+            assert_eq!(input.lines().count(), 50, "{}", day.name);
+            for part in [Part::One, Part::Two] {
+                assert!(
+                    day.solve_str(part, &input).is_ok(),
+                    "{} part {}",
+                    day.name,
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stress_inputs_solve() {
+        let options = GenerateOptions {
+            lines: Some(2000),
+            valid_density: 0.5,
+            ..GenerateOptions::default()
+        };
+        for day in days().filter(|day| day.generator.is_some()) {
+            let input = generate(day, 1, &options).unwrap();
+            for part in [Part::One, Part::Two] {
+                assert!(
+                    day.solve_str(part, &input).is_ok(),
+                    "{} part {}",
+                    day.name,
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn missing_generator() {
+        let day = Day {
+            year: 2024,
+            number: 25,
+            name: "day25",
+            variants: find(2024, "day1").unwrap().variants,
+            generator: None,
+        };
+        assert_eq!(
+            generate(&day, 1, &GenerateOptions::default()).unwrap_err(),
+            NoGeneratorError {
+                year: 2024,
+                day: "day25"
+            }
+        );
+    }
+}
@@ -1,41 +1,100 @@
-use core::panic;
-use std::fmt::Debug;
-use clap::{Parser, Subcommand};
+//! Advent of Code solutions, usable as a CLI (`aoc`) or as a library.
+//!
+//! The stable library surface is [`solve`] to run one part of a day on an
+//! input, and [`days`] (or [`years`]) to enumerate what is registered:
+//!
+//! ```
+//! use aoc::Part;
+//!
+//! for day in aoc::days() {
+//!     println!("{} {}", day.year(), day.name());
+//! }
+//! let answer = aoc::solve(2024, 3, Part::One, "mul(2,4)mul(3,7)").unwrap();
+//! assert_eq!(answer.to_string(), "29");
+//! ```
+//!
+//! Days can also be implemented outside the crate against [`Run`] or
+//! [`RunStr`]. Everything in the hidden `cli` module exists for the `aoc`
+//! binary and may change at any time.
 mod runner;
-mod day1;
-pub use runner::Run;
-use runner::RunFile;
-mod day2;
-mod day3;
+mod answer;
+mod registry;
+mod batch;
+mod timing;
+mod config;
+mod answers;
+mod client;
+mod submit;
+mod fixtures;
+mod scaffold;
+mod bench;
+mod output;
+mod generate;
+mod watch;
+mod solve;
+#[doc(hidden)]
+pub mod cli;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+pub use answer::Answer;
+pub use runner::{InputParseError, Part, Position, Run, RunFile, RunStr};
+pub use solve::{solve, Error};
+pub use registry::{
+    days, find, year, years, CrossCheckError, Day, UnknownDayError, UnknownVariantError,
+    UnknownYearError, Variant, Year,
+};
 
+/// Declares each `dayN` module of a year and registers its `Runner` in the
+/// year's `DAYS` as the `default` variant, followed by any alternatives listed
+/// as `{ name: Type }`. `with generate` registers `dayN::generate` as the
+/// day's input generator.
+macro_rules! days {
+    (@generator) => {
+        None
+    };
+    (@generator $generator:path) => {
+        Some($generator)
+    };
+    (year = $year:literal; $($number:literal => $day:ident $(with $generator:ident)? $({ $($variant:ident: $runner:ident),* $(,)? })?),* $(,)?) => {
+        $(pub(crate) mod $day;)*
 
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-pub struct Cli {
-    #[command(subcommand)]
-    pub command: Option<Commands>,
-
-    #[arg(global=true)]
-    pub input: Option<String>,
-
-    #[arg(short, long, global=true, default_value_t=false)]
-    pub part2: bool,
+        pub(crate) static DAYS: &[$crate::Day] = &[
+            $($crate::Day {
+                year: $year,
+                number: $number,
+                name: stringify!($day),
+                variants: &[
+                    $crate::Variant {
+                        name: "default",
+                        runner: &$day::Runner,
+                    },
+                    $($($crate::Variant {
+                        name: stringify!($variant),
+                        runner: &$day::$runner,
+                    },)*)?
+                ],
+                generator: days!(@generator $($day::$generator)?),
+            },)*
+        ];
+    };
 }
 
-#[derive(Subcommand, Debug)]
-pub enum Commands {
-    Day1,
-    Day2,
-    Day3,
-}
+/// Declares each `yYYYY` module and registers its days in `YEARS`.
+macro_rules! years {
+    ($($year:literal => $module:ident),* $(,)?) => {
+        $(mod $module;)*
 
-
-pub fn to_runner(command: &Option<Commands>) -> Box<dyn RunFile> {
-    match command {
-        Some(Commands::Day1) => Box::new(day1::Runner),
-        Some(Commands::Day2) => Box::new(day2::Runner),
-        Some(Commands::Day3) => Box::new(day3::Runner),
-        None => panic!("Not a valid command runner")
-    }
+        static YEARS: &[Year] = &[
+            $(Year {
+                number: $year,
+                name: stringify!($year),
+                days: $module::DAYS,
+            },)*
+        ];
+    };
 }
 
+years! {
+    2024 => y2024,
+}
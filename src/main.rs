@@ -1,20 +1,435 @@
 use std::error::Error;
-use std::io::BufReader;
+use std::io::{self, BufRead, BufReader, Read, Write};
 use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use clap::Parser;
-use aoc;
+use aoc::{Answer, Day, Part, Variant};
+use aoc::cli::{
+    self, AnswerStore, Baseline, Attempt, Cli, Client, Commands, Config, DayCommand, FetchOutcome, Format, GenerateOptions, InputArg,
+    RunReport, SubmissionLog, Verdict, Watcher,
+};
 
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let cli = aoc::Cli::parse();
-    let input_path = &cli.input.ok_or("No Input provided!")?; 
-    let f = File::open(input_path)?;
-    let runner = aoc::to_runner(&cli.command);
-    let result = match cli.part2 {
-        false => runner.run(BufReader::new(f))?,
-        true => runner.run2(BufReader::new(f))?
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let year = match &cli.command {
+        Some(Commands::Day(DayCommand { year: Some(year), .. })) => Some(*year),
+        _ => cli.year,
+    };
+    let config = Config::load_for_year(year)?;
+    match &cli.command {
+        Some(Commands::Day(_)) | None => (),
+        _ if cli.watch => return Err("--watch only applies to running a single day".into()),
+        _ => (),
+    }
+    match &cli.command {
+        Some(Commands::Day(_) | Commands::Submit { .. } | Commands::Check { .. }) | None => (),
+        Some(Commands::Bench { .. }) if cli.cross_check => {
+            return Err("--cross-check cannot be used with bench; pick a variant with --variant".into())
+        }
+        Some(Commands::Bench { .. }) => (),
+        _ if cli.variant.is_some() || cli.cross_check => {
+            return Err("--variant and --cross-check only apply to commands that solve a single day".into())
+        }
+        _ => (),
+    }
+    let (day, input) = match cli.command {
+        Some(Commands::List) => {
+            for day in aoc::year(config.year)?.days() {
+                println!("{}", day.name());
+            }
+            return Ok(());
+        }
+        Some(Commands::All) => {
+            aoc::year(config.year)?;
+            return run_all(&config, jobs(cli.jobs), cli.time, cli.format);
+        }
+        Some(Commands::Verify) => {
+            aoc::year(config.year)?;
+            return verify(&config, jobs(cli.jobs));
+        }
+        Some(Commands::Fetch { day }) => return fetch(&config, day),
+        Some(Commands::Submit { day, input }) => {
+            let day = aoc::find(config.year, &day)?;
+            let reader = open_input(&config, day, input.input, cli.example)?;
+            let part = Part::from_flag(cli.part2);
+            let answer = answer(day, cli.variant.as_deref(), cli.cross_check, part, reader)?;
+            return submit(&config, day, part, answer);
+        }
+        Some(Commands::Bench {
+            day,
+            input: InputArg { input: path },
+            warmup,
+            samples,
+            save_baseline,
+            threshold,
+        }) => {
+            let day = aoc::find(config.year, &day)?;
+            let variant = day.variant(cli.variant.as_deref())?;
+            let mut input = Vec::new();
+            open_input(&config, day, path, cli.example)?.read_to_end(&mut input)?;
+            let options = BenchOptions {
+                warmup,
+                samples,
+                save_baseline,
+                threshold: threshold / 100.0,
+            };
+            return bench(&config, day, variant, Part::from_flag(cli.part2), &input, options);
+        }
+        Some(Commands::Extract { day, page }) => return extract(&config, day, &page),
+        Some(Commands::Generate {
+            day,
+            seed,
+            lines,
+            safe_ratio,
+            valid_density,
+            near_miss_density,
+        }) => {
+            let options = GenerateOptions {
+                lines,
+                safe_ratio,
+                valid_density,
+                near_miss_density,
+            };
+            return generate(aoc::find(config.year, &day)?, seed, &options);
+        }
+        Some(Commands::New { day, template }) => {
+            for path in cli::new_day(Path::new("."), &config, day, template)? {
+                println!("Created {}", path.display());
+            }
+            return Ok(());
+        }
+        Some(Commands::Check { day, input }) => {
+            let day = aoc::find(config.year, &day)?;
+            let reader = open_input(&config, day, input.input, cli.example)?;
+            let part = Part::from_flag(cli.part2);
+            let answer = answer(day, cli.variant.as_deref(), cli.cross_check, part, reader)?;
+            return check(&config, day, part, answer);
+        }
+        Some(Commands::Day(day)) => (aoc::year(config.year)?.find(&day.name)?, day.input),
+        None => return Err("No day provided! Run `aoc list` to see the registered days".into()),
     };
-    println!("{}", result);
+    let options = RunOptions {
+        part: Part::from_flag(cli.part2),
+        variant: cli.variant,
+        cross_check: cli.cross_check,
+        time: cli.time,
+        format: cli.format,
+    };
+    if cli.watch {
+        let path = input_path(&config, day, input, cli.example)?;
+        return watch(day, &options, &path);
+    }
+    let report = solve(day, &options, open_input(&config, day, input, cli.example)?)?;
+    print_report(&report, &options);
+    match report.result {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+struct RunOptions {
+    part: Part,
+    variant: Option<String>,
+    cross_check: bool,
+    time: bool,
+    format: Format,
+}
+
+fn solve(
+    day: &'static Day,
+    options: &RunOptions,
+    mut reader: Box<dyn BufRead>,
+) -> Result<RunReport, Box<dyn Error>> {
+    let part = options.part;
+    let (result, timings): (Result<Answer, Box<dyn Error + Send + Sync>>, _) =
+        match options.cross_check {
+            false => {
+                let variant = day.variant(options.variant.as_deref())?;
+                let (result, timings) = cli::record(|| variant.solve(part, reader));
+                (result.map_err(|e| e.into()), timings)
+            }
+            true => {
+                let mut input = Vec::new();
+                reader.read_to_end(&mut input)?;
+                let (result, timings) = cli::record(|| day.cross_check(part, &input));
+                (result.map_err(|e| e.into()), timings)
+            }
+        };
+    Ok(RunReport {
+        day,
+        part,
+        result,
+        timings,
+    })
+}
+
+fn print_report(report: &RunReport, options: &RunOptions) {
+    match (options.format, &report.result) {
+        (Format::Text, Ok(answer)) if options.time => {
+            println!("{}  ({})", answer, report.timings)
+        }
+        (Format::Text, Ok(answer)) => println!("{}", answer),
+        (Format::Text, Err(_)) => (),
+        (format, _) => println!("{}", cli::render_run(format, report)),
+    }
+}
+
+/// Re-solves whenever the input file changes, until the process is killed.
+fn watch(day: &'static Day, options: &RunOptions, path: &Path) -> Result<(), Box<dyn Error>> {
+    if path.to_str() == Some("-") {
+        return Err("--watch needs an input file, not stdin".into());
+    }
+    day.variant(options.variant.as_deref())?;
+    let mut watcher = Watcher::new(path);
+    eprintln!("Watching {} for changes", watcher.path().display());
+    if let Some(header) = cli::watch_header(options.format) {
+        println!("{}", header);
+    }
+    loop {
+        // One failed poll or run, e.g. the file being replaced mid-read, is
+        // reported and the next change is waited for.
+        let run = watcher.changed().map_err(|e| e.into()).and_then(|changed| {
+            changed
+                .then(|| solve(day, options, open_input_path(path)?))
+                .transpose()
+        });
+        let now = cli::unix_now();
+        match run {
+            Ok(Some(report)) => match (options.format, &report.result) {
+                (Format::Text, Ok(_)) => {
+                    print!("[{}] ", cli::clock(now));
+                    print_report(&report, options);
+                }
+                (format, _) => println!("{}", cli::render_run_at(format, &report, now)),
+            },
+            Ok(None) => (),
+            Err(e) => eprintln!("[{}] error: {}", cli::clock(now), e),
+        }
+        thread::sleep(Duration::from_millis(200));
+    }
+}
+
+fn input_path(
+    config: &Config,
+    day: &Day,
+    input: Option<String>,
+    example: Option<u32>,
+) -> Result<PathBuf, Box<dyn Error>> {
+    match (input, example) {
+        (Some(_), Some(_)) => Err("Pass either an input path or --example, not both".into()),
+        (Some(input), None) => Ok(PathBuf::from(input)),
+        (None, example) => Ok(config.input_path(day.number(), example)),
+    }
+}
+
+fn open_input_path(input_path: &Path) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    match input_path.to_str() {
+        Some("-") => Ok(Box::new(io::stdin().lock())),
+        _ => {
+            let f = File::open(input_path)
+                .map_err(|e| format!("{}: {}", input_path.display(), e))?;
+            Ok(Box::new(BufReader::new(f)))
+        }
+    }
+}
+
+fn open_input(
+    config: &Config,
+    day: &Day,
+    input: Option<String>,
+    example: Option<u32>,
+) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    open_input_path(&input_path(config, day, input, example)?)
+}
+
+fn jobs(jobs: Option<usize>) -> usize {
+    jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+fn run_all(
+    config: &Config,
+    jobs: usize,
+    time: bool,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let reports = cli::run_all(config, jobs);
+    match (format, time) {
+        (Format::Text, true) => {
+            println!("{}", RunReport::header());
+            for report in &reports {
+                println!("{}  ({})", report, report.timings);
+            }
+        }
+        (format, _) => println!("{}", cli::render_runs(format, &reports)),
+    }
+    if format == Format::Text {
+        for report in &reports {
+            if let Err(e) = &report.result {
+                eprintln!("\n{} part {}: {}", report.day.name(), report.part, e);
+            }
+        }
+    }
+    let failed = reports.iter().filter(|report| report.result.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{} of {} runs failed", failed, reports.len()).into());
+    }
+    Ok(())
+}
+
+fn verify(config: &Config, jobs: usize) -> Result<(), Box<dyn Error>> {
+    let store = AnswerStore::load(&config.answers_file)?;
+    let verdicts: Vec<Verdict> = cli::run_all(config, jobs)
+        .iter()
+        .map(|report| {
+            let verdict = Verdict::check(&store, report);
+            println!("{} part {}: {}", report.day.name(), report.part, verdict);
+            verdict
+        })
+        .collect();
+    let failed = verdicts.iter().filter(|verdict| verdict.is_failure()).count();
+    if failed > 0 {
+        return Err(format!("{} of {} answers did not verify", failed, verdicts.len()).into());
+    }
+    Ok(())
+}
+
+fn fetch(config: &Config, day: u8) -> Result<(), Box<dyn Error>> {
+    let path = config.input_path(day, None);
+    let client = Client::from_config(config)?;
+    match client.fetch_input_to(config.year, day, &path)? {
+        FetchOutcome::Downloaded => println!("Downloaded {}", path.display()),
+        FetchOutcome::Cached => println!("Already cached at {}", path.display()),
+    }
+    Ok(())
+}
+
+/// The answer `submit` and `check` work with: the selected variant's, or with
+/// `--cross-check` the one every variant agrees on.
+fn answer(
+    day: &Day,
+    variant: Option<&str>,
+    cross_check: bool,
+    part: Part,
+    mut reader: Box<dyn BufRead>,
+) -> Result<Answer, Box<dyn Error>> {
+    if cross_check {
+        let mut input = Vec::new();
+        reader.read_to_end(&mut input)?;
+        return Ok(day.cross_check(part, &input)?);
+    }
+    Ok(day.variant(variant)?.solve(part, reader)?)
+}
+
+fn submit(config: &Config, day: &Day, part: Part, answer: Answer) -> Result<(), Box<dyn Error>> {
+    let mut log = SubmissionLog::load(&config.submissions_file)?;
+    if let Some(wait) = log.cooldown(cli::unix_now()) {
+        return Err(format!("Submitted too recently; {}s left to wait", wait.as_secs()).into());
+    }
+    log.check(day.year(), day.number(), part, &answer)?;
+    let answer = answer.to_string();
+    let client = Client::from_config(config)?;
+    let outcome = client.submit(config.year, day.number(), part, &answer)?;
+    println!("{}: {}", answer, outcome);
+    log.record(Attempt {
+        year: day.year(),
+        day: day.number(),
+        part,
+        answer,
+        outcome,
+        time: cli::unix_now(),
+    })?;
     Ok(())
 }
 
+fn check(config: &Config, day: &Day, part: Part, answer: Answer) -> Result<(), Box<dyn Error>> {
+    let log = SubmissionLog::load(&config.submissions_file)?;
+    let bounds = log.bounds(day.year(), day.number(), part);
+    if let Some(bound) = bounds.too_low {
+        println!("Answer must be greater than {}", bound);
+    }
+    if let Some(bound) = bounds.too_high {
+        println!("Answer must be less than {}", bound);
+    }
+    log.check(day.year(), day.number(), part, &answer)?;
+    println!("{}: not rejected before", answer);
+    Ok(())
+}
+
+fn extract(config: &Config, day: u8, page: &Path) -> Result<(), Box<dyn Error>> {
+    let html = std::fs::read_to_string(page).map_err(|e| format!("{}: {}", page.display(), e))?;
+    let examples = cli::extract_examples(&html);
+    if examples.is_empty() {
+        return Err(format!("No examples found in {}", page.display()).into());
+    }
+    for path in cli::write_fixtures(&config.fixtures_dir, day, &examples)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn generate(day: &Day, seed: Option<u64>, options: &GenerateOptions) -> Result<(), Box<dyn Error>> {
+    let seed = seed.unwrap_or_else(|| {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        now.as_nanos() as u64
+    });
+    let input = cli::generate(day, seed, options)?;
+    eprintln!("{} seed {}", day.name(), seed);
+    io::stdout().write_all(input.as_bytes())?;
+    Ok(())
+}
+
+struct BenchOptions {
+    warmup: usize,
+    samples: usize,
+    save_baseline: bool,
+    threshold: f64,
+}
+
+fn bench(
+    config: &Config,
+    day: &Day,
+    variant: &Variant,
+    part: Part,
+    input: &[u8],
+    options: BenchOptions,
+) -> Result<(), Box<dyn Error>> {
+    let stats = cli::bench(variant, part, input, options.warmup, options.samples)?;
+    match variant.name() {
+        "default" => println!("{} part {}: {}", day.name(), part, stats),
+        name => println!("{} ({}) part {}: {}", day.name(), name, part, stats),
+    }
+    let mut baseline = Baseline::load(&config.bench_file)?;
+    match baseline.get(day.number(), variant.name(), part) {
+        Some(previous) if !options.save_baseline => {
+            let change = stats.mean.as_secs_f64() / previous.as_secs_f64() - 1.0;
+            println!("baseline mean {:.2?} ({:+.1}%)", previous, change * 100.0);
+            if cli::regression(stats.mean, previous, options.threshold).is_some() {
+                return Err(format!(
+                    "Regression: mean is more than {:.0}% slower than the baseline",
+                    options.threshold * 100.0
+                )
+                .into());
+            }
+        }
+        _ if stats.mean.is_zero() => println!("Mean is zero; not saving it as the baseline"),
+        _ => {
+            baseline.save(day.number(), variant.name(), part, stats.mean)?;
+            println!("Saved baseline to {}", config.bench_file.display());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use clap::ValueEnum;
+
+use crate::answer::Answer;
+use crate::batch::RunReport;
+use crate::watch::clock;
+
+/// How run results are printed.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Human-readable answers and tables
+    #[default]
+    Text,
+    /// One JSON object per run, in an array for multi-day runs
+    Json,
+    /// A header row followed by one row per run
+    Csv,
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// `timestamp` is the unix time of a `--watch` re-run, and is left out otherwise.
+fn json(report: &RunReport, timestamp: Option<u64>) -> String {
+    let answer = match &report.result {
+        Ok(Answer::Integer(n)) => n.to_string(),
+        Ok(Answer::Signed(n)) => n.to_string(),
+        Ok(answer) => json_string(&answer.to_string()),
+        Err(_) => String::from("null"),
+    };
+    let error = match &report.result {
+        Ok(_) => String::from("null"),
+        Err(e) => json_string(&e.to_string()),
+    };
+    // An array rather than an object keyed by name, since phases repeat when
+    // several variants run, e.g. under `--cross-check`.
+    let phases: Vec<String> = report
+        .timings
+        .phases
+        .iter()
+        .map(|phase| {
+            format!(
+                "{{\"name\":{},\"ns\":{}}}",
+                json_string(phase.name),
+                phase.elapsed.as_nanos()
+            )
+        })
+        .collect();
+    let timestamp = timestamp.map_or(String::new(), |t| format!("\"timestamp\":{},", t));
+    format!(
+        "{{{}\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"phases\":[{}],\"error\":{}}}",
+        timestamp,
+        report.day.year,
+        report.day.number,
+        report.part,
+        answer,
+        report.timings.total.as_nanos(),
+        phases.join(","),
+        error
+    )
+}
+
+pub const CSV_HEADER: &str = "year,day,part,answer,elapsed_ns,phases_ns,error";
+
+fn csv(report: &RunReport, timestamp: Option<u64>) -> String {
+    let (answer, error) = match &report.result {
+        Ok(answer) => (answer.to_string(), String::new()),
+        Err(e) => (String::new(), e.to_string()),
+    };
+    let phases: Vec<String> = report
+        .timings
+        .phases
+        .iter()
+        .map(|phase| format!("{}={}", phase.name, phase.elapsed.as_nanos()))
+        .collect();
+    let timestamp = timestamp.map_or(String::new(), |t| format!("{},", t));
+    format!(
+        "{}{},{},{},{},{},{},{}",
+        timestamp,
+        report.day.year,
+        report.day.number,
+        report.part,
+        csv_field(&answer),
+        report.timings.total.as_nanos(),
+        csv_field(&phases.join(";")),
+        csv_field(&error)
+    )
+}
+
+/// Renders a single run: a JSON object, or a CSV header and one row.
+pub fn render_run(format: Format, report: &RunReport) -> String {
+    match format {
+        Format::Text => match &report.result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {}", e),
+        },
+        Format::Json => json(report, None),
+        Format::Csv => format!("{}\n{}", CSV_HEADER, csv(report, None)),
+    }
+}
+
+/// Printed once when `--watch` starts, before any `render_run_at` output: the
+/// CSV header, as every re-run is a single row.
+pub fn watch_header(format: Format) -> Option<String> {
+    match format {
+        Format::Csv => Some(format!("timestamp,{}", CSV_HEADER)),
+        Format::Text | Format::Json => None,
+    }
+}
+
+/// Renders a `--watch` re-run like `render_run`, stamped with the unix time
+/// `at`: a `[HH:MM:SS]` prefix for text, a leading `timestamp` field otherwise.
+/// CSV rows come without a header; see `watch_header`.
+pub fn render_run_at(format: Format, report: &RunReport, at: u64) -> String {
+    match format {
+        Format::Text => format!("[{}] {}", clock(at), render_run(format, report)),
+        Format::Json => json(report, Some(at)),
+        Format::Csv => csv(report, Some(at)),
+    }
+}
+
+/// Renders a multi-day run: a JSON array, or a CSV header and one row per run.
+pub fn render_runs(format: Format, reports: &[RunReport]) -> String {
+    let rows = reports.iter().map(|report| match format {
+        Format::Text => report.to_string(),
+        Format::Json => json(report, None),
+        Format::Csv => csv(report, None),
+    });
+    match format {
+        Format::Text => std::iter::once(RunReport::header())
+            .chain(rows)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Format::Json => format!("[{}]", rows.collect::<Vec<_>>().join(",")),
+        Format::Csv => std::iter::once(CSV_HEADER.to_string())
+            .chain(rows)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::{InputParseError, Part};
+    use crate::timing::{Phase, Timings};
+    use std::time::Duration;
+
+    fn report(result: Result<Answer, InputParseError>) -> RunReport {
+        RunReport {
+            day: crate::registry::find(2024, "day1").unwrap(),
+            part: Part::Two,
+            result: result.map_err(|e| e.into()),
+            timings: Timings {
+                phases: vec![
+                    Phase {
+                        name: "parse",
+                        elapsed: Duration::from_nanos(40),
+                    },
+                    Phase {
+                        name: "solve",
+                        elapsed: Duration::from_nanos(60),
+                    },
+                ],
+                total: Duration::from_nanos(120),
+            },
+        }
+    }
+
+    #[test]
+    fn json_answer() {
+        assert_eq!(
+            json(&report(Ok(Answer::Integer(31))), None),
+            "{\"year\":2024,\"day\":1,\"part\":2,\"answer\":31,\"elapsed_ns\":120,\
+             \"phases\":[{\"name\":\"parse\",\"ns\":40},{\"name\":\"solve\",\"ns\":60}],\
+             \"error\":null}"
+        );
+        assert!(json(&report(Ok(Answer::from("a\"b"))), None).contains("\"answer\":\"a\\\"b\""));
+    }
+
+    #[test]
+    fn json_repeated_phases() {
+        let mut report = report(Ok(Answer::Integer(31)));
+        report.timings.phases.extend(report.timings.phases.clone());
+        assert!(json(&report, None).contains(
+            "\"phases\":[{\"name\":\"parse\",\"ns\":40},{\"name\":\"solve\",\"ns\":60},\
+             {\"name\":\"parse\",\"ns\":40},{\"name\":\"solve\",\"ns\":60}]"
+        ));
+    }
+
+    #[test]
+    fn json_error() {
+        let output = json(&report(Err(InputParseError::new("Bad input"))), None);
+        assert!(output.contains("\"answer\":null"));
+        assert!(output.ends_with("\"error\":\"Bad input\"}"));
+    }
+
+    #[test]
+    fn json_runs() {
+        let reports = [
+            report(Ok(Answer::Integer(31))),
+            report(Ok(Answer::Signed(-2))),
+        ];
+        let output = render_runs(Format::Json, &reports);
+        assert!(output.starts_with("[{\"year\":2024,\"day\":1,"));
+        assert!(output.contains("},{"));
+        assert!(output.contains("\"answer\":-2"));
+        assert!(output.ends_with("}]"));
+    }
+
+    #[test]
+    fn watch_timestamp() {
+        let report = report(Ok(Answer::Integer(31)));
+        assert_eq!(
+            render_run_at(Format::Text, &report, 1_733_050_861),
+            "[11:01:01] 31"
+        );
+        assert!(render_run_at(Format::Json, &report, 1_733_050_861)
+            .starts_with("{\"timestamp\":1733050861,\"year\":2024,"));
+        assert_eq!(
+            watch_header(Format::Csv).unwrap(),
+            "timestamp,year,day,part,answer,elapsed_ns,phases_ns,error"
+        );
+        assert_eq!(
+            render_run_at(Format::Csv, &report, 1_733_050_861),
+            "1733050861,2024,1,2,31,120,parse=40;solve=60,"
+        );
+        assert_eq!(watch_header(Format::Json), None);
+    }
+
+    #[test]
+    fn csv_row() {
+        assert_eq!(
+            csv(&report(Ok(Answer::Integer(31))), None),
+            "2024,1,2,31,120,parse=40;solve=60,"
+        );
+        assert_eq!(
+            csv(&report(Ok(Answer::from("1,2"))), None),
+            "2024,1,2,\"1,2\",120,parse=40;solve=60,"
+        );
+        assert_eq!(
+            render_run(Format::Csv, &report(Err(InputParseError::new("Bad \"input\"")))),
+            "year,day,part,answer,elapsed_ns,phases_ns,error\n2024,1,2,,120,parse=40;solve=60,\"Bad \"\"input\"\"\""
+        );
+    }
+}
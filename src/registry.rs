@@ -0,0 +1,623 @@
+use std::{error::Error, fmt::Display, io::BufRead};
+
+use clap::{value_parser, ArgMatches, Args as _, Command, FromArgMatches, Subcommand};
+
+use crate::answer::Answer;
+use crate::generate::Generator;
+use crate::runner::{input_str, read_input, InputParseError, Part, RunStr};
+
+/// One implementation of a day. Every day has a `default` variant, its `Runner`.
+pub struct Variant {
+    pub(crate) name: &'static str,
+    pub(crate) runner: &'static dyn RunStr,
+}
+
+impl std::fmt::Debug for Variant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Variant").field("name", &self.name).finish()
+    }
+}
+
+impl Variant {
+    /// The name passed to `--variant`, `default` for the day's `Runner`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Solves one part, reading the whole input first.
+    pub fn solve(&self, part: Part, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        self.solve_str(part, &read_input(reader)?)
+    }
+
+    /// Solves one part of an input that is already in memory.
+    pub fn solve_str(&self, part: Part, input: &str) -> Result<Answer, InputParseError> {
+        match part {
+            Part::One => self.runner.run(input),
+            Part::Two => self.runner.run2(input),
+        }
+    }
+}
+
+/// A puzzle day known to the CLI, registered through the `days!` macro in `lib.rs`.
+pub struct Day {
+    pub(crate) year: u16,
+    pub(crate) number: u8,
+    pub(crate) name: &'static str,
+    /// The `default` variant first, then any alternatives in registration order.
+    pub(crate) variants: &'static [Variant],
+    /// Produces random inputs for `aoc generate`, if the day has one.
+    pub(crate) generator: Option<Generator>,
+}
+
+impl std::fmt::Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Day")
+            .field("year", &self.year)
+            .field("number", &self.number)
+            .field("name", &self.name)
+            .field("variants", &self.variants)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Day {
+    /// The event the day belongs to, e.g. `2024`.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The day of the month, `1` to `25`.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// The module and subcommand name, e.g. `"day3"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The `default` variant first, then any alternatives in registration order.
+    pub fn variants(&self) -> &'static [Variant] {
+        self.variants
+    }
+
+    /// Solves one part with the `default` variant.
+    pub fn solve(&self, part: Part, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        self.variants[0].solve(part, reader)
+    }
+
+    /// Like [`Day::solve`], for an input that is already in memory.
+    pub fn solve_str(&self, part: Part, input: &str) -> Result<Answer, InputParseError> {
+        self.variants[0].solve_str(part, input)
+    }
+
+    /// Like [`Day::solve_str`], rejecting input that is not UTF-8.
+    pub fn solve_bytes(&self, part: Part, input: &[u8]) -> Result<Answer, InputParseError> {
+        self.solve_str(part, input_str(input)?)
+    }
+
+    /// The named variant, or `default` when no name is given.
+    pub fn variant(&self, name: Option<&str>) -> Result<&'static Variant, UnknownVariantError> {
+        let name = name.unwrap_or("default");
+        self.variants
+            .iter()
+            .find(|variant| variant.name == name)
+            .ok_or_else(|| UnknownVariantError {
+                day: self.name,
+                name: name.to_string(),
+                known: self.variants.iter().map(|variant| variant.name).collect(),
+            })
+    }
+
+    /// Runs every variant on the same input and returns their shared result.
+    /// Parse errors count as results, so variants must also agree on rejecting an input.
+    pub fn cross_check(&self, part: Part, input: &[u8]) -> Result<Answer, CrossCheckError> {
+        let input = input_str(input).map_err(CrossCheckError::Parse)?;
+        let results: Vec<(&'static str, Result<Answer, InputParseError>)> = self
+            .variants
+            .iter()
+            .map(|variant| (variant.name, variant.solve_str(part, input)))
+            .collect();
+        if results.iter().any(|(_, result)| *result != results[0].1) {
+            return Err(CrossCheckError::Mismatch {
+                day: self.name,
+                part,
+                results,
+            });
+        }
+        let (_, result) = results
+            .into_iter()
+            .next()
+            .expect("Every day has a default variant");
+        result.map_err(CrossCheckError::Parse)
+    }
+}
+
+/// `--variant` named a variant the day does not have.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UnknownVariantError {
+    /// The day that was asked for the variant.
+    pub day: &'static str,
+    /// The requested variant.
+    pub name: String,
+    /// The variants the day does have.
+    pub known: Vec<&'static str>,
+}
+
+impl Display for UnknownVariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown variant for {}: {} (expected one of: {})",
+            self.day,
+            self.name,
+            self.known.join(", ")
+        )
+    }
+}
+
+impl Error for UnknownVariantError {}
+
+/// Why [`Day::cross_check`] could not settle on one answer.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CrossCheckError {
+    /// Every variant rejected the input with the same error.
+    Parse(InputParseError),
+    /// At least two variants produced different results.
+    Mismatch {
+        /// The day whose variants disagree.
+        day: &'static str,
+        /// The part that was solved.
+        part: Part,
+        /// Each variant's name and result, `default` first.
+        results: Vec<(&'static str, Result<Answer, InputParseError>)>,
+    },
+}
+
+impl Display for CrossCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrossCheckError::Parse(e) => write!(f, "{}", e),
+            CrossCheckError::Mismatch { day, part, results } => {
+                write!(f, "Variants of {} part {} disagree:", day, part)?;
+                for (name, result) in results {
+                    match result {
+                        Ok(answer) => write!(f, "\n  {}: {}", name, answer)?,
+                        Err(e) => write!(
+                            f,
+                            "\n  {}: error: {}",
+                            name,
+                            e.to_string().lines().next().unwrap_or_default()
+                        )?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for CrossCheckError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CrossCheckError::Parse(e) => Some(e),
+            CrossCheckError::Mismatch { .. } => None,
+        }
+    }
+}
+
+/// An event whose days are registered through the `years!` macro in `lib.rs`.
+#[derive(Debug)]
+pub struct Year {
+    pub(crate) number: u16,
+    /// The year as a subcommand name, e.g. `"2024"`.
+    pub(crate) name: &'static str,
+    pub(crate) days: &'static [Day],
+}
+
+impl Year {
+    /// The event, e.g. `2024`.
+    pub fn number(&self) -> u16 {
+        self.number
+    }
+
+    /// The registered days in registration order.
+    pub fn days(&self) -> &'static [Day] {
+        self.days
+    }
+
+    /// Finds a day of this year by name (`day3`) or number (`3`).
+    pub fn find(&self, name: &str) -> Result<&'static Day, UnknownDayError> {
+        let days: &'static [Day] = self.days;
+        days.iter()
+            .find(|day| day.name == name || day.number.to_string() == name)
+            .ok_or_else(|| UnknownDayError {
+                year: self.number,
+                name: name.to_string(),
+            })
+    }
+}
+
+/// No day with the given name or number is registered for the year.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UnknownDayError {
+    /// The year that was searched.
+    pub year: u16,
+    /// The day as it was asked for, e.g. `"day26"` or `"26"`.
+    pub name: String,
+}
+
+impl Display for UnknownDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown day for {}: {}", self.year, self.name)
+    }
+}
+
+impl Error for UnknownDayError {}
+
+/// No days are registered for the year.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UnknownYearError {
+    /// The year that was asked for.
+    pub year: u16,
+}
+
+impl Display for UnknownYearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let known: Vec<String> = years().iter().map(|year| year.number.to_string()).collect();
+        write!(
+            f,
+            "No days registered for {} (registered years: {})",
+            self.year,
+            known.join(", ")
+        )
+    }
+}
+
+impl Error for UnknownYearError {}
+
+/// Every registered year, in registration order.
+pub fn years() -> &'static [Year] {
+    crate::YEARS
+}
+
+/// The registered year with the given number.
+pub fn year(number: u16) -> Result<&'static Year, UnknownYearError> {
+    years()
+        .iter()
+        .find(|year| year.number == number)
+        .ok_or(UnknownYearError { year: number })
+}
+
+/// Every registered day, year by year in registration order.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    years().iter().flat_map(|year| year.days)
+}
+
+/// Finds a day by year and by name (`day3`) or number (`3`). An unregistered
+/// year is reported as an unknown day.
+pub fn find(year: u16, name: &str) -> Result<&'static Day, UnknownDayError> {
+    match self::year(year) {
+        Ok(year) => year.find(name),
+        Err(_) => Err(UnknownDayError {
+            year,
+            name: name.to_string(),
+        }),
+    }
+}
+
+/// One subcommand per day name registered in any year (`aoc day3`, or `aoc 3`),
+/// plus one per year wrapping that year's days (`aoc 2024 day3`). Flattened
+/// into `Commands`; the day is looked up once the year is known. Any other
+/// subcommand is kept as an unknown day, or as a year when it looks like one,
+/// so `aoc 2023 day1` reports that 2023 has no days.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct DayCommand {
+    /// The year subcommand, when one was given.
+    pub year: Option<u16>,
+    /// The day as typed, e.g. `day3` or `3`.
+    pub name: String,
+    /// The input path given after the day, `-` for stdin.
+    pub input: Option<String>,
+}
+
+fn day_subcommands(cmd: Command, days: impl Iterator<Item = &'static Day>) -> Command {
+    let mut seen: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    let mut days: Vec<&Day> = days.collect();
+    days.sort_by_key(|day| day.number);
+    days.into_iter().fold(cmd, |cmd, day| {
+        if seen.iter().any(|name| name == day.name) {
+            return cmd;
+        }
+        seen.push(day.name.to_string());
+        cmd.subcommand(
+            crate::cli::InputArg::augment_args(Command::new(day.name))
+                .alias(day.number.to_string())
+                .about(format!("Run the solution for day {}", day.number)),
+        )
+    })
+}
+
+/// A four-digit number no earlier than the first event, 2015. Smaller numbers
+/// are day numbers.
+fn looks_like_year(name: &str) -> Option<u16> {
+    let year: u16 = name.parse().ok()?;
+    (name.len() == 4 && year >= 2015).then_some(year)
+}
+
+impl FromArgMatches for DayCommand {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let invalid =
+            |message: String| clap::Error::raw(clap::error::ErrorKind::InvalidSubcommand, message);
+        let input = |sub: &ArgMatches| crate::cli::InputArg::from_arg_matches(sub).map(|arg| arg.input);
+        match matches.subcommand() {
+            Some((name, sub)) if years().iter().any(|year| year.name == name) => {
+                let year = name.parse().unwrap_or_default();
+                let (day, sub) = sub
+                    .subcommand()
+                    .ok_or_else(|| invalid(format!("No day given for {}", year)))?;
+                Ok(DayCommand {
+                    year: Some(year),
+                    name: day.to_string(),
+                    input: input(sub)?,
+                })
+            }
+            Some((name, sub)) if days().any(|day| day.name == name) => Ok(DayCommand {
+                year: None,
+                name: name.to_string(),
+                input: input(sub)?,
+            }),
+            // Anything else arrives as an external subcommand with raw arguments.
+            Some((name, sub)) => {
+                let mut args = sub.get_many::<String>("").into_iter().flatten().cloned();
+                match looks_like_year(name) {
+                    Some(year) => Ok(DayCommand {
+                        year: Some(year),
+                        name: args.next().unwrap_or_default(),
+                        input: args.next(),
+                    }),
+                    None => Ok(DayCommand {
+                        year: None,
+                        name: name.to_string(),
+                        input: args.next(),
+                    }),
+                }
+            }
+            None => Err(invalid(String::from("No day given"))),
+        }
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Subcommand for DayCommand {
+    fn augment_subcommands(cmd: Command) -> Command {
+        let cmd = day_subcommands(cmd, days())
+            .allow_external_subcommands(true)
+            .external_subcommand_value_parser(value_parser!(String));
+        years().iter().fold(cmd, |cmd, year| {
+            cmd.subcommand(day_subcommands(
+                Command::new(year.name)
+                    .about(format!("Run a solution from {}", year.number))
+                    .subcommand_required(true),
+                year.days.iter(),
+            ))
+        })
+    }
+
+    fn augment_subcommands_for_update(cmd: Command) -> Command {
+        Self::augment_subcommands(cmd)
+    }
+
+    /// Every name but the other `Commands`, which the derive only tries after
+    /// this flattened one. Unknown names are reported once the year is known.
+    fn has_subcommand(name: &str) -> bool {
+        let registered = years().iter().any(|year| year.name == name)
+            || days().any(|day| day.name == name || day.number.to_string() == name);
+        registered
+            || crate::cli::Commands::augment_subcommands(Command::new("aoc"))
+                .find_subcommand(name)
+                .is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn find_by_name_or_number() {
+        assert_eq!(find(2024, "day2").unwrap().number, 2);
+        assert_eq!(find(2024, "3").unwrap().name, "day3");
+        assert_eq!(find(2024, "3").unwrap().year, 2024);
+    }
+
+    #[test]
+    fn solve_in_memory() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        let day = find(2024, "day1").unwrap();
+        assert_eq!(day.solve_str(Part::One, input).unwrap(), 11);
+        assert_eq!(day.solve_bytes(Part::Two, input.as_bytes()).unwrap(), 31);
+        assert_eq!(day.solve(Part::One, input.as_bytes()).unwrap(), 11);
+        assert_eq!(
+            day.solve_bytes(Part::One, b"3   4\n\xff").unwrap_err().message,
+            "Input is not valid UTF-8"
+        );
+
+        // day3 borrows the whole input, so a `mul` split across lines is not read.
+        let day = find(2024, "day3").unwrap();
+        assert_eq!(day.solve(Part::One, &b"mul(2,4)mul(3\n,7)"[..]).unwrap(), 8);
+    }
+
+    #[test]
+    fn select_variant() {
+        let day = find(2024, "day2").unwrap();
+        assert_eq!(day.variant(None).unwrap().name, "default");
+        assert_eq!(day.variant(Some("linear")).unwrap().name, "linear");
+        assert_eq!(
+            day.variant(Some("fast")).unwrap_err().to_string(),
+            "Unknown variant for day2: fast (expected one of: default, linear)"
+        );
+    }
+
+    static MIXED: &[Variant] = &[
+        Variant {
+            name: "default",
+            runner: &crate::y2024::day1::Runner,
+        },
+        Variant {
+            name: "copy",
+            runner: &crate::y2024::day1::Runner,
+        },
+        Variant {
+            name: "other",
+            runner: &crate::y2024::day2::Runner,
+        },
+    ];
+
+    #[test]
+    fn cross_check_variants() {
+        let agreeing = Day {
+            year: 2024,
+            number: 1,
+            name: "day1",
+            variants: &MIXED[..2],
+            generator: None,
+        };
+        assert_eq!(
+            agreeing.cross_check(Part::One, b"3   4\n4   3\n"),
+            Ok(Answer::Integer(0))
+        );
+        assert!(matches!(
+            agreeing.cross_check(Part::One, b"3   x\n"),
+            Err(CrossCheckError::Parse(_))
+        ));
+
+        let mixed = Day {
+            year: 2024,
+            number: 1,
+            name: "day1",
+            variants: MIXED,
+            generator: None,
+        };
+        let error = mixed.cross_check(Part::One, b"3   4\n4   3\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Variants of day1 part 1 disagree:\n  default: 0\n  copy: 0\n  other: 2"
+        );
+    }
+
+    #[test]
+    fn parse_day_commands() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["aoc", "2024", "day3", "input.txt", "-p"]).unwrap();
+        assert!(cli.part2);
+        assert_eq!(
+            parse_day(&["aoc", "2024", "day3", "input.txt", "-p"]),
+            DayCommand {
+                year: Some(2024),
+                name: String::from("day3"),
+                input: Some(String::from("input.txt")),
+            }
+        );
+        assert_eq!(
+            parse_day(&["aoc", "-p", "day1"]),
+            DayCommand {
+                year: None,
+                name: String::from("day1"),
+                input: None,
+            }
+        );
+        assert_eq!(
+            parse_day(&["aoc", "2024", "3", "input.txt"]),
+            DayCommand {
+                year: Some(2024),
+                name: String::from("day3"),
+                input: Some(String::from("input.txt")),
+            }
+        );
+        let cli = Cli::try_parse_from(["aoc", "-y", "2024", "3", "input.txt", "-p"]).unwrap();
+        assert_eq!((cli.year, cli.part2), (Some(2024), true));
+        assert_eq!(
+            parse_day(&["aoc", "-y", "2024", "3", "input.txt", "-p"]),
+            DayCommand {
+                year: None,
+                name: String::from("day3"),
+                input: Some(String::from("input.txt")),
+            }
+        );
+        assert!(Cli::try_parse_from(["aoc", "2024"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "list", "input.txt"]).is_err());
+        assert!(matches!(
+            Cli::try_parse_from(["aoc", "list"]).unwrap().command,
+            Some(Commands::List)
+        ));
+        assert!(matches!(
+            Cli::try_parse_from(["aoc", "fetch", "3"]).unwrap().command,
+            Some(Commands::Fetch { day: 3 })
+        ));
+    }
+
+    fn parse_day(args: &[&str]) -> DayCommand {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        match Cli::try_parse_from(args).unwrap().command {
+            Some(Commands::Day(day)) => day,
+            command => panic!("Expected a day command, got {:?}", command),
+        }
+    }
+
+    #[test]
+    fn parse_unknown_year() {
+        // Not read as an input path followed by the 2024 day1.
+        assert_eq!(
+            parse_day(&["aoc", "9999", "day1", "x"]),
+            DayCommand {
+                year: Some(9999),
+                name: String::from("day1"),
+                input: Some(String::from("x")),
+            }
+        );
+        assert_eq!(year(9999).unwrap_err(), UnknownYearError { year: 9999 });
+        assert_eq!(
+            parse_day(&["aoc", "day99"]),
+            DayCommand {
+                year: None,
+                name: String::from("day99"),
+                input: None,
+            }
+        );
+        // Too small to be a year, so an unknown day of the selected year.
+        assert_eq!(
+            parse_day(&["aoc", "26", "x"]),
+            DayCommand {
+                year: None,
+                name: String::from("26"),
+                input: Some(String::from("x")),
+            }
+        );
+    }
+
+    #[test]
+    fn find_unknown() {
+        assert_eq!(
+            find(2024, "day99").unwrap_err(),
+            UnknownDayError {
+                year: 2024,
+                name: String::from("day99")
+            }
+        );
+        assert_eq!(find(1999, "day1").unwrap_err().year, 1999);
+        assert_eq!(year(1999).unwrap_err(), UnknownYearError { year: 1999 });
+    }
+}
@@ -1,44 +1,288 @@
-use std::{
-    error::Error,
-    fmt::Display,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::{error::Error, fmt::Display, io::BufRead, sync::Arc};
 
+use crate::answer::Answer;
+
+/// Where in the input a parse error occurred. `line` and `column` are 1-based;
+/// `line` is `None` until `on_line` records it.
 #[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Position {
+    /// The line of the input, if the error was raised while parsing one.
+    pub line: Option<usize>,
+    /// The column within the line, counted in characters.
+    pub column: usize,
+}
+
+/// An input a day could not parse, with where and why when known.
+#[derive(Debug, Clone)]
 pub struct InputParseError {
+    /// What was wrong, e.g. `"Expected a number"`.
     pub message: &'static str,
+    /// Where the offending token is, if it was marked with `at_token` or `on_line`.
+    pub position: Option<Position>,
+    /// The offending token, underlined when the error is displayed.
+    pub token: Option<String>,
+    /// The full input line the token was found on.
+    pub source_line: Option<String>,
+    cause: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl InputParseError {
+    /// An error with only a message; chain the other methods to locate it.
+    pub fn new(message: &'static str) -> InputParseError {
+        InputParseError {
+            message,
+            position: None,
+            token: None,
+            source_line: None,
+            cause: None,
+        }
+    }
+
+    /// Marks `token`, found at the 1-based `column` of its line, as the offending text.
+    pub fn at_token(mut self, column: usize, token: &str) -> InputParseError {
+        let line = self.position.as_ref().and_then(|p| p.line);
+        self.position = Some(Position { line, column });
+        self.token = Some(token.to_string());
+        self
+    }
+
+    /// Records the 1-based line number and text of the line being parsed.
+    pub fn on_line(mut self, line_number: usize, line: &str) -> InputParseError {
+        let column = self.position.as_ref().map_or(1, |p| p.column);
+        self.position = Some(Position {
+            line: Some(line_number),
+            column,
+        });
+        self.source_line = Some(line.to_string());
+        self
+    }
+
+    /// Attaches the underlying error, e.g. from `str::parse`.
+    pub fn with_cause(mut self, cause: impl Error + Send + Sync + 'static) -> InputParseError {
+        self.cause = Some(Arc::new(cause));
+        self
+    }
+}
+
+impl PartialEq for InputParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+            && self.position == other.position
+            && self.token == other.token
+            && self.source_line == other.source_line
+            && self.cause.as_ref().map(|c| c.to_string())
+                == other.cause.as_ref().map(|c| c.to_string())
+    }
 }
 
+impl Eq for InputParseError {}
+
 impl Display for InputParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Failed to parse input")
+        write!(f, "{}", self.message)?;
+        // With a source line the cause goes after the underline instead.
+        let snippet = self.source_line.as_ref().zip(self.position.as_ref());
+        if let (None, Some(cause)) = (snippet, &self.cause) {
+            write!(f, ": {}", cause)?;
+        }
+        let Some(position) = &self.position else {
+            return Ok(());
+        };
+        let Some(line) = position.line else {
+            return write!(f, "\n --> column {}", position.column);
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        write!(f, "\n{} --> line {}, column {}", gutter, line, position.column)?;
+        if let Some((source_line, position)) = snippet {
+            let underline = "^".repeat(self.token.as_ref().map_or(0, |t| t.chars().count()).max(1));
+            write!(f, "\n{} |", gutter)?;
+            write!(f, "\n{} | {}", line, source_line)?;
+            write!(
+                f,
+                "\n{} | {}{}",
+                gutter,
+                " ".repeat(position.column.saturating_sub(1)),
+                underline
+            )?;
+            if let Some(cause) = &self.cause {
+                write!(f, " {}", cause)?;
+            }
+        }
+        Ok(())
     }
 }
 
-impl Error for InputParseError {}
+impl Error for InputParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.cause
+            .as_ref()
+            .map(|cause| cause.as_ref() as &(dyn Error + 'static))
+    }
+}
+
+/// Splits `line` on whitespace, pairing each token with its 1-based column.
+pub fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count() + 1, token)
+    })
+}
+
+/// Which half of a puzzle to solve.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Part {
+    /// The first part, unlocked with the input.
+    One,
+    /// The second part, unlocked by solving the first.
+    Two,
+}
+
+impl Part {
+    /// `Part::Two` when the `-p` flag is set, `Part::One` otherwise.
+    pub fn from_flag(part2: bool) -> Part {
+        match part2 {
+            false => Part::One,
+            true => Part::Two,
+        }
+    }
 
+    /// `1` or `2`, as the site numbers the parts.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A day that parses its input line by line from a reader.
 pub trait Run {
-    fn run(&self, reader: impl BufRead) -> Result<u64, InputParseError>;
+    /// Solves part one.
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError>;
+
+    /// Solves part two.
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError>;
+}
+
+/// Zero-copy form of `Run`: the whole input is borrowed at once, so a parser
+/// can return slices of it instead of allocating a `String` per line. This is
+/// what the registry calls; registered days are shared between the threads of
+/// a multi-day run, hence `Send + Sync`.
+pub trait RunStr: Send + Sync {
+    /// Solves part one.
+    fn run(&self, input: &str) -> Result<Answer, InputParseError>;
+
+    /// Solves part two.
+    fn run2(&self, input: &str) -> Result<Answer, InputParseError>;
+}
 
-    fn run2(&self, reader: impl BufRead) -> Result<u64, InputParseError>;
+/// `Run` days read the borrowed input through the `BufRead` impl of `&[u8]`.
+impl<T> RunStr for T
+where
+    T: Run + Send + Sync,
+{
+    fn run(&self, input: &str) -> Result<Answer, InputParseError> {
+        Run::run(self, input.as_bytes())
+    }
+
+    fn run2(&self, input: &str) -> Result<Answer, InputParseError> {
+        Run::run2(self, input.as_bytes())
+    }
 }
 
-pub trait RunFile {
-    fn run(&self, reader: BufReader<File>) -> Result<u64, InputParseError>;
+/// Object-safe form taking any reader (a file, stdin or an in-memory buffer).
+/// The input is read into memory first and handed to `RunStr`. The registry
+/// goes through `Day::solve` instead; this stays public for callers that hold
+/// their own days as `&dyn RunFile` and feed them whatever reader they have.
+pub trait RunFile: Send + Sync {
+    /// Solves part one.
+    fn run(&self, reader: &mut dyn BufRead) -> Result<Answer, InputParseError>;
 
-    fn run2(&self, reader: BufReader<File>) -> Result<u64, InputParseError>;
+    /// Solves part two.
+    fn run2(&self, reader: &mut dyn BufRead) -> Result<Answer, InputParseError>;
 }
 
 impl<T> RunFile for T
 where
-    T: Run,
+    T: RunStr + ?Sized,
 {
-    fn run(&self, reader: BufReader<File>) -> Result<u64, InputParseError> {
-        Run::run(self, reader)
+    fn run(&self, reader: &mut dyn BufRead) -> Result<Answer, InputParseError> {
+        RunStr::run(self, &read_input(reader)?)
+    }
+
+    fn run2(&self, reader: &mut dyn BufRead) -> Result<Answer, InputParseError> {
+        RunStr::run2(self, &read_input(reader)?)
+    }
+}
+
+pub(crate) fn read_input(mut reader: impl BufRead) -> Result<String, InputParseError> {
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|e| InputParseError::new("Failed to read input").with_cause(e))?;
+    Ok(input)
+}
+
+pub(crate) fn input_str(input: &[u8]) -> Result<&str, InputParseError> {
+    std::str::from_utf8(input)
+        .map_err(|e| InputParseError::new("Input is not valid UTF-8").with_cause(e))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn token_columns() {
+        let result: Vec<(usize, &str)> = tokens("3   4  x").collect();
+        assert_eq!(result, vec![(1, "3"), (5, "4"), (8, "x")]);
+    }
+
+    #[test]
+    fn display_snippet() {
+        let cause = "x9".parse::<u32>().unwrap_err();
+        let error = InputParseError::new("Could not parse number")
+            .at_token(5, "x9")
+            .with_cause(cause)
+            .on_line(12, "3   x9");
+        assert_eq!(
+            error.to_string(),
+            "Could not parse number\n   \
+             --> line 12, column 5\n   \
+             |\n\
+             12 | 3   x9\n   \
+             |     ^^ invalid digit found in string"
+        );
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn display_without_position() {
+        let error = InputParseError::new("Failed to read line");
+        assert_eq!(error.to_string(), "Failed to read line");
     }
 
-    fn run2(&self, reader: BufReader<File>) -> Result<u64, InputParseError> {
-        Run::run2(self, reader)
+    #[test]
+    fn display_cause_without_source_line() {
+        let cause = "x9".parse::<u32>().unwrap_err();
+        let error = InputParseError::new("Could not parse number")
+            .at_token(5, "x9")
+            .with_cause(cause);
+        assert_eq!(error.position, Some(Position { line: None, column: 5 }));
+        assert_eq!(
+            error.to_string(),
+            "Could not parse number: invalid digit found in string
+ --> column 5"
+        );
     }
 }
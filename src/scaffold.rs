@@ -0,0 +1,268 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+
+use crate::config::Config;
+
+const DAYS_BLOCK: &str = "days! {\n";
+const YEARS_BLOCK: &str = "years! {\n";
+
+/// Starting point for a new day, chosen by the shape of the puzzle input.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, ValueEnum)]
+pub enum Template {
+    /// One list of whitespace-separated numbers per line, like day1 and day2
+    #[default]
+    Lines,
+    /// The whole input as one borrowed string, like day3
+    Blob,
+    /// A 2D grid of characters
+    Grid,
+    /// Two blocks of lines separated by a blank line
+    Sections,
+}
+
+impl Template {
+    fn source(self) -> &'static str {
+        match self {
+            Template::Lines => include_str!("../templates/lines.rs"),
+            Template::Blob => include_str!("../templates/blob.rs"),
+            Template::Grid => include_str!("../templates/grid.rs"),
+            Template::Sections => include_str!("../templates/sections.rs"),
+        }
+    }
+}
+
+/// A day that could not be created or registered.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ScaffoldError {
+    /// What went wrong.
+    pub message: String,
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ScaffoldError {}
+
+fn io_error(path: &Path) -> impl Fn(io::Error) -> ScaffoldError + '_ {
+    move |e| ScaffoldError {
+        message: format!("{}: {}", path.display(), e),
+    }
+}
+
+/// Appends `line` as the last entry of the `block` macro invocation in `contents`.
+fn insert_into_block(
+    contents: &str,
+    block: &str,
+    line: &str,
+    path: &Path,
+) -> Result<String, ScaffoldError> {
+    let start = contents.find(block).ok_or(ScaffoldError {
+        message: format!(
+            "No `{}` block found in {}",
+            block.trim_end(),
+            path.display()
+        ),
+    })?;
+    let end = start
+        + contents[start..].find("\n}").ok_or(ScaffoldError {
+            message: format!(
+                "Unterminated `{}` block in {}",
+                block.trim_end(),
+                path.display()
+            ),
+        })?
+        + 1;
+    let mut contents = contents.to_string();
+    contents.insert_str(end, line);
+    Ok(contents)
+}
+
+/// Creates `src/yYYYY/dayN.rs` under `root` for the configured year,
+/// registers it in the year's `days!` block (adding the year module to the
+/// `years!` block of `src/lib.rs` first if needed) and creates empty input and
+/// fixture files. Returns the paths it created or changed.
+pub fn new_day(
+    root: &Path,
+    config: &Config,
+    day: u8,
+    template: Template,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError {
+            message: format!("Day must be between 1 and 25, got {}", day),
+        });
+    }
+    let year = config.year;
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let module = year_dir.join(format!("day{}.rs", day));
+    let year_module = year_dir.join("mod.rs");
+    let lib = root.join("src").join("lib.rs");
+    let registration = format!("    {} => day{},\n", day, day);
+
+    let year_contents = match fs::read_to_string(&year_module) {
+        Ok(contents) => Some(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(io_error(&year_module)(e)),
+    };
+    if module.exists()
+        || year_contents
+            .as_ref()
+            .is_some_and(|contents| contents.contains(&registration))
+    {
+        return Err(ScaffoldError {
+            message: format!("{} day{} already exists", year, day),
+        });
+    }
+    // Check every registry before writing anything, so a failure leaves the tree untouched.
+    let lib_contents = match year_contents {
+        Some(_) => None,
+        None => {
+            let contents = fs::read_to_string(&lib).map_err(io_error(&lib))?;
+            let line = format!("    {} => y{},\n", year, year);
+            Some(insert_into_block(&contents, YEARS_BLOCK, &line, &lib)?)
+        }
+    };
+    let year_contents = year_contents.unwrap_or_else(|| {
+        format!(
+            "//! Advent of Code {}.\n\n{}    year = {};\n}}\n",
+            year, DAYS_BLOCK, year
+        )
+    });
+    let year_contents = insert_into_block(&year_contents, DAYS_BLOCK, &registration, &year_module)?;
+
+    let mut created = Vec::new();
+    fs::create_dir_all(&year_dir).map_err(io_error(&year_dir))?;
+    fs::write(
+        &module,
+        template
+            .source()
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &day.to_string()),
+    )
+    .map_err(io_error(&module))?;
+    created.push(module);
+
+    fs::write(&year_module, year_contents).map_err(io_error(&year_module))?;
+    created.push(year_module);
+    if let Some(lib_contents) = lib_contents {
+        fs::write(&lib, lib_contents).map_err(io_error(&lib))?;
+        created.push(lib);
+    }
+
+    let fixtures = &config.fixtures_dir;
+    // `aoc fetch` fills the empty input in, since it only skips non-empty ones.
+    let files = [
+        config.input_path(day, None),
+        crate::fixtures::example_path(fixtures, day, 1),
+        fixtures.join(format!("day{:02}.answers.toml", day)),
+    ];
+    for path in files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error(dir))?;
+        }
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => created.push(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => (),
+            Err(e) => return Err(io_error(&path)(e)),
+        }
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_day_registers_module() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2024")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "years! {\n    2024 => y2024,\n}\n\nstruct Cli;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/y2024/mod.rs"),
+            "days! {\n    year = 2024;\n    1 => day1,\n}\n",
+        )
+        .unwrap();
+        let config = Config {
+            input_dir: root.join("inputs"),
+            fixtures_dir: root.join("fixtures"),
+            ..Config::default()
+        };
+
+        let created = new_day(&root, &config, 4, Template::Grid).unwrap();
+        assert_eq!(created.len(), 5);
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2024/mod.rs")).unwrap(),
+            "days! {\n    year = 2024;\n    1 => day1,\n    4 => day4,\n}\n"
+        );
+        let module = fs::read_to_string(root.join("src/y2024/day4.rs")).unwrap();
+        assert!(module.contains("check_examples(2024, 4, &Runner)"));
+        assert!(module.contains("struct Grid"));
+        assert!(root.join("inputs/day04.txt").exists());
+        assert!(root.join("fixtures/day04.example1.txt").exists());
+
+        let error = new_day(&root, &config, 4, Template::Lines).unwrap_err();
+        assert_eq!(error.message, "2024 day4 already exists");
+
+        let config = Config {
+            year: 2023,
+            ..config
+        };
+        new_day(&root, &config, 1, Template::Lines).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "years! {\n    2024 => y2024,\n    2023 => y2023,\n}\n\nstruct Cli;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap(),
+            "//! Advent of Code 2023.\n\ndays! {\n    year = 2023;\n    1 => day1,\n}\n"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn fetch_after_new_downloads_input() {
+        use crate::client::{test::stub_server, Client, FetchOutcome};
+
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-fetch-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2024")).unwrap();
+        fs::write(root.join("src/lib.rs"), "years! {\n    2024 => y2024,\n}\n").unwrap();
+        fs::write(
+            root.join("src/y2024/mod.rs"),
+            "days! {\n    year = 2024;\n}\n",
+        )
+        .unwrap();
+        let config = Config {
+            input_dir: root.join("inputs"),
+            fixtures_dir: root.join("fixtures"),
+            ..Config::default()
+        };
+        new_day(&root, &config, 4, Template::Blob).unwrap();
+
+        let (base_url, server) = stub_server(vec![(200, "mul(2,4)\n")]);
+        let path = config.input_path(4, None);
+        assert_eq!(
+            Client::new(&base_url, "abc123")
+                .fetch_input_to(2024, 4, &path)
+                .unwrap(),
+            FetchOutcome::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "mul(2,4)\n");
+        server.join().unwrap();
+        fs::remove_dir_all(root).unwrap();
+    }
+}
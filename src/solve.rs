@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use crate::answer::Answer;
+use crate::registry::{year, UnknownDayError, UnknownYearError};
+use crate::runner::{InputParseError, Part};
+
+/// Everything `solve` can fail with.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Error {
+    /// No days are registered for the year.
+    UnknownYear(UnknownYearError),
+    /// The year exists but the day is not registered in it.
+    UnknownDay(UnknownDayError),
+    /// The day rejected the input.
+    Parse(InputParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownYear(e) => write!(f, "{}", e),
+            Error::UnknownDay(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UnknownYear(e) => Some(e),
+            Error::UnknownDay(e) => Some(e),
+            Error::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<UnknownYearError> for Error {
+    fn from(e: UnknownYearError) -> Self {
+        Error::UnknownYear(e)
+    }
+}
+
+impl From<UnknownDayError> for Error {
+    fn from(e: UnknownDayError) -> Self {
+        Error::UnknownDay(e)
+    }
+}
+
+impl From<InputParseError> for Error {
+    fn from(e: InputParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// Solves one part of a registered day with its default variant.
+///
+/// ```
+/// use aoc::Part;
+///
+/// let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+/// assert_eq!(aoc::solve(2024, 1, Part::One, input).unwrap(), 11);
+/// assert!(aoc::solve(2024, 1, Part::One, "3 x\n").is_err());
+/// assert!(aoc::solve(1999, 1, Part::One, input).is_err());
+/// ```
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Result<Answer, Error> {
+    let number = day;
+    let day = self::year(year)?
+        .days()
+        .iter()
+        .find(|day| day.number() == number)
+        .ok_or_else(|| UnknownDayError {
+            year,
+            name: number.to_string(),
+        })?;
+    Ok(day.solve_str(part, input)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solve_by_number() {
+        let input = "7 6 4 2 1\n1 2 7 8 9\n1 3 2 4 5\n";
+        assert_eq!(solve(2024, 2, Part::One, input).unwrap(), 1);
+        assert_eq!(solve(2024, 2, Part::Two, input).unwrap(), 2);
+    }
+
+    #[test]
+    fn solve_errors() {
+        assert_eq!(
+            solve(1999, 1, Part::One, ""),
+            Err(Error::UnknownYear(UnknownYearError { year: 1999 }))
+        );
+        assert_eq!(
+            solve(2024, 26, Part::One, ""),
+            Err(Error::UnknownDay(UnknownDayError {
+                year: 2024,
+                name: String::from("26")
+            }))
+        );
+        assert!(matches!(
+            solve(2024, 1, Part::One, "3   x\n"),
+            Err(Error::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,607 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::answer::Answer;
+use crate::config::ConfigError;
+use crate::runner::Part;
+
+/// How the puzzle site responded to a submitted answer.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SubmitOutcome {
+    /// The answer was right.
+    Correct,
+    /// The answer was wrong, with no hint.
+    Wrong {
+        /// Time before another answer is accepted, if the site said.
+        wait: Option<Duration>,
+    },
+    /// The answer was wrong and too high.
+    TooHigh {
+        /// Time before another answer is accepted, if the site said.
+        wait: Option<Duration>,
+    },
+    /// The answer was wrong and too low.
+    TooLow {
+        /// Time before another answer is accepted, if the site said.
+        wait: Option<Duration>,
+    },
+    /// The answer was not checked because the last one was too recent.
+    RateLimited {
+        /// Time before another answer is accepted.
+        wait: Duration,
+    },
+    /// The part was solved before.
+    AlreadySolved,
+    /// A response that was not recognized; holds its text.
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    /// Parses the HTML page returned after posting an answer.
+    pub fn parse(html: &str) -> SubmitOutcome {
+        let text = article_text(html);
+        let wait = parse_wait(&text);
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("You gave an answer too recently") {
+            SubmitOutcome::RateLimited {
+                wait: wait.unwrap_or_default(),
+            }
+        } else if text.contains("your answer is too high") {
+            SubmitOutcome::TooHigh { wait }
+        } else if text.contains("your answer is too low") {
+            SubmitOutcome::TooLow { wait }
+        } else if text.contains("That's not the right answer") {
+            SubmitOutcome::Wrong { wait }
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unknown(text)
+        }
+    }
+
+    /// How long the site asked us to wait before submitting again.
+    pub fn wait(&self) -> Option<Duration> {
+        match self {
+            SubmitOutcome::Wrong { wait }
+            | SubmitOutcome::TooHigh { wait }
+            | SubmitOutcome::TooLow { wait } => *wait,
+            SubmitOutcome::RateLimited { wait } => Some(*wait),
+            _ => None,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::Wrong { .. } => "wrong",
+            SubmitOutcome::TooHigh { .. } => "too_high",
+            SubmitOutcome::TooLow { .. } => "too_low",
+            SubmitOutcome::RateLimited { .. } => "rate_limited",
+            SubmitOutcome::AlreadySolved => "already_solved",
+            SubmitOutcome::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_key(key: &str, wait: Option<Duration>) -> SubmitOutcome {
+        match key {
+            "correct" => SubmitOutcome::Correct,
+            "wrong" => SubmitOutcome::Wrong { wait },
+            "too_high" => SubmitOutcome::TooHigh { wait },
+            "too_low" => SubmitOutcome::TooLow { wait },
+            "rate_limited" => SubmitOutcome::RateLimited {
+                wait: wait.unwrap_or_default(),
+            },
+            "already_solved" => SubmitOutcome::AlreadySolved,
+            other => SubmitOutcome::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::Wrong { .. } => write!(f, "That's not the right answer"),
+            SubmitOutcome::TooHigh { .. } => write!(f, "That's not the right answer; too high"),
+            SubmitOutcome::TooLow { .. } => write!(f, "That's not the right answer; too low"),
+            SubmitOutcome::RateLimited { wait } => {
+                write!(f, "Answered too recently; {}s left to wait", wait.as_secs())
+            }
+            SubmitOutcome::AlreadySolved => write!(f, "This part is already solved"),
+            SubmitOutcome::Unknown(text) => write!(f, "Unrecognized response: {}", text),
+        }
+    }
+}
+
+/// Text content of the page's `<article>`, with tags removed.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Finds "You have 1m 23s left to wait" or "please wait one minute/5 minutes".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(start) = text.find("You have ") {
+        let rest = &text[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+        let secs = rest[..end]
+            .split_whitespace()
+            .map(|unit| {
+                if let Some(n) = unit.strip_suffix('h') {
+                    n.parse::<u64>().ok().map(|n| n * 3600)
+                } else if let Some(n) = unit.strip_suffix('m') {
+                    n.parse::<u64>().ok().map(|n| n * 60)
+                } else {
+                    unit.strip_suffix('s')?.parse::<u64>().ok()
+                }
+            })
+            .sum::<Option<u64>>()?;
+        return Some(Duration::from_secs(secs));
+    }
+    let text = text.to_lowercase();
+    let start = text.find("please wait ")?;
+    let mut words = text[start + "please wait ".len()..].split_whitespace();
+    let amount = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(amount * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(amount)),
+        _ => None,
+    }
+}
+
+/// Seconds since the Unix epoch, `0` if the clock is set before it.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// One submitted answer and its outcome.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Attempt {
+    /// The event the answer was for.
+    pub year: u16,
+    /// The day of the month.
+    pub day: u8,
+    /// The part that was answered.
+    pub part: Part,
+    /// The answer as it was sent.
+    pub answer: String,
+    /// How the site judged it.
+    pub outcome: SubmitOutcome,
+    /// Unix time the answer was submitted.
+    pub time: u64,
+}
+
+/// Every submitted answer, persisted as `[[attempt]]` tables in a TOML file.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    /// Attempts in the order they were made.
+    pub attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Reads the log at `path`; a missing file is an empty log.
+    pub fn load(path: &Path) -> Result<SubmissionLog, ConfigError> {
+        let error = |message: String| ConfigError {
+            path: path.to_path_buf(),
+            message,
+        };
+        let attempts = match fs::read_to_string(path) {
+            Ok(contents) => parse_attempts(&contents).map_err(error)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(error(e.to_string())),
+        };
+        Ok(SubmissionLog {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    /// Appends an attempt and writes the whole log back.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), ConfigError> {
+        self.attempts.push(attempt);
+        let error = |e: std::io::Error| ConfigError {
+            path: self.path.clone(),
+            message: e.to_string(),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+        fs::write(&self.path, format_attempts(&self.attempts)).map_err(error)
+    }
+
+    /// Time left before the site accepts another answer, based on the last attempt.
+    pub fn cooldown(&self, now: u64) -> Option<Duration> {
+        let last = self.attempts.last()?;
+        let until = last.time + last.outcome.wait()?.as_secs();
+        (until > now).then(|| Duration::from_secs(until - now))
+    }
+
+    /// Answers known to be wrong for a day and part, as `(lowest too-high, highest too-low)`.
+    pub fn bounds(&self, year: u16, day: u8, part: Part) -> Bounds {
+        self.attempts
+            .iter()
+            .filter(|attempt| attempt.year == year && attempt.day == day && attempt.part == part)
+            .fold(Bounds::default(), |bounds, attempt| {
+                let Ok(value) = attempt.answer.parse::<i128>() else {
+                    return bounds;
+                };
+                match attempt.outcome {
+                    SubmitOutcome::TooHigh { .. } => Bounds {
+                        too_high: Some(bounds.too_high.map_or(value, |b| b.min(value))),
+                        ..bounds
+                    },
+                    SubmitOutcome::TooLow { .. } => Bounds {
+                        too_low: Some(bounds.too_low.map_or(value, |b| b.max(value))),
+                        ..bounds
+                    },
+                    _ => bounds,
+                }
+            })
+    }
+
+    /// Refuses answers that were already rejected or fall outside known bounds.
+    /// Attempts the site did not judge (rate limited, already solved or an
+    /// unrecognized page) do not count as submitted.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<(), Rejection> {
+        let rendered = answer.to_string();
+        let previous = self.attempts.iter().find(|attempt| {
+            attempt.year == year
+                && attempt.day == day
+                && attempt.part == part
+                && attempt.answer == rendered
+                && !matches!(
+                    attempt.outcome,
+                    SubmitOutcome::RateLimited { .. }
+                        | SubmitOutcome::AlreadySolved
+                        | SubmitOutcome::Unknown(_)
+                )
+        });
+        if let Some(attempt) = previous {
+            return Err(Rejection::AlreadySubmitted(attempt.outcome.clone()));
+        }
+        let value = match answer {
+            Answer::Integer(n) => i128::from(*n),
+            Answer::Signed(n) => i128::from(*n),
+            _ => return Ok(()),
+        };
+        let bounds = self.bounds(year, day, part);
+        match bounds {
+            Bounds {
+                too_high: Some(bound),
+                ..
+            } if value >= bound => Err(Rejection::TooHigh { bound }),
+            Bounds {
+                too_low: Some(bound),
+                ..
+            } if value <= bound => Err(Rejection::TooLow { bound }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Limits on a numeric answer learned from earlier wrong ones.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct Bounds {
+    /// The lowest answer the site called too high.
+    pub too_high: Option<i128>,
+    /// The highest answer the site called too low.
+    pub too_low: Option<i128>,
+}
+
+/// Why an answer should not be submitted, according to the submission log.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Rejection {
+    /// The same answer was submitted before, with this outcome.
+    AlreadySubmitted(SubmitOutcome),
+    /// The answer is not below an earlier too-high answer.
+    TooHigh {
+        /// The earlier too-high answer.
+        bound: i128,
+    },
+    /// The answer is not above an earlier too-low answer.
+    TooLow {
+        /// The earlier too-low answer.
+        bound: i128,
+    },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySubmitted(outcome) => {
+                write!(f, "Answer was already submitted: {}", outcome)
+            }
+            Rejection::TooHigh { bound } => {
+                write!(f, "Answer is too high; {} was already too high", bound)
+            }
+            Rejection::TooLow { bound } => {
+                write!(f, "Answer is too low; {} was already too low", bound)
+            }
+        }
+    }
+}
+
+impl Error for Rejection {}
+
+fn parse_attempts(contents: &str) -> Result<Vec<Attempt>, String> {
+    let table = contents
+        .parse::<toml::Table>()
+        .map_err(|e| e.message().to_string())?;
+    let Some(attempts) = table.get("attempt") else {
+        return Ok(Vec::new());
+    };
+    attempts
+        .as_array()
+        .ok_or("Expected [[attempt]] tables")?
+        .iter()
+        .map(|attempt| {
+            let int = |key: &str| {
+                attempt
+                    .get(key)
+                    .and_then(|v| v.as_integer())
+                    .ok_or(format!("Attempt is missing {}", key))
+            };
+            let string = |key: &str| {
+                attempt
+                    .get(key)
+                    .and_then(|v| v.as_str())
+                    .ok_or(format!("Attempt is missing {}", key))
+            };
+            let invalid = |key: &str| format!("Attempt has an invalid {}", key);
+            let wait = match int("wait") {
+                Ok(wait) => Some(Duration::from_secs(
+                    u64::try_from(wait).map_err(|_| invalid("wait"))?,
+                )),
+                Err(_) => None,
+            };
+            Ok(Attempt {
+                year: u16::try_from(int("year")?).map_err(|_| invalid("year"))?,
+                day: u8::try_from(int("day")?).map_err(|_| invalid("day"))?,
+                part: match int("part")? {
+                    1 => Part::One,
+                    2 => Part::Two,
+                    _ => return Err(invalid("part")),
+                },
+                answer: string("answer")?.to_string(),
+                outcome: match string("outcome")? {
+                    "unknown" => {
+                        SubmitOutcome::Unknown(string("text").unwrap_or_default().to_string())
+                    }
+                    key => SubmitOutcome::from_key(key, wait),
+                },
+                time: u64::try_from(int("time")?).map_err(|_| invalid("time"))?,
+            })
+        })
+        .collect()
+}
+
+fn format_attempts(attempts: &[Attempt]) -> String {
+    let attempts = attempts
+        .iter()
+        .map(|attempt| {
+            let mut table = toml::Table::new();
+            table.insert("year".into(), i64::from(attempt.year).into());
+            table.insert("day".into(), i64::from(attempt.day).into());
+            table.insert("part".into(), i64::from(attempt.part.number()).into());
+            table.insert("answer".into(), attempt.answer.clone().into());
+            table.insert("outcome".into(), attempt.outcome.key().into());
+            if let Some(wait) = attempt.outcome.wait() {
+                table.insert("wait".into(), (wait.as_secs() as i64).into());
+            }
+            if let SubmitOutcome::Unknown(text) = &attempt.outcome {
+                table.insert("text".into(), text.clone().into());
+            }
+            table.insert("time".into(), (attempt.time as i64).into());
+            toml::Value::Table(table)
+        })
+        .collect();
+    let mut root = toml::Table::new();
+    root.insert("attempt".into(), toml::Value::Array(attempts));
+    root.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_correct() {
+        let html = "<html><main><article><p>That's the right answer! You are \
+                    <em>one gold star</em> closer.</p></article></main></html>";
+        assert_eq!(SubmitOutcome::parse(html), SubmitOutcome::Correct);
+    }
+
+    #[test]
+    fn parse_too_high() {
+        let html = "<article><p>That's not the right answer; your answer is too high. \
+                    Please wait one minute before trying again.</p></article>";
+        assert_eq!(
+            SubmitOutcome::parse(html),
+            SubmitOutcome::TooHigh {
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+    }
+
+    #[test]
+    fn parse_too_low() {
+        let html = "<article><p>That's not the right answer; your answer is too low. \
+                    please wait 5 minutes before trying again.</p></article>";
+        assert_eq!(
+            SubmitOutcome::parse(html),
+            SubmitOutcome::TooLow {
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+    }
+
+    #[test]
+    fn parse_wrong() {
+        let html = "<article><p>That's not the right answer. If you're stuck, \
+                    make sure you're using the full input data.</p></article>";
+        assert_eq!(
+            SubmitOutcome::parse(html),
+            SubmitOutcome::Wrong { wait: None }
+        );
+    }
+
+    #[test]
+    fn parse_rate_limited() {
+        let html = "<article><p>You gave an answer too recently; you have to wait \
+                    after submitting an answer before trying again.  \
+                    You have 1m 23s left to wait.</p></article>";
+        assert_eq!(
+            SubmitOutcome::parse(html),
+            SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(83)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_already_solved() {
+        let html = "<article><p>You don't seem to be solving the right level.  \
+                    Did you already complete it?</p></article>";
+        assert_eq!(SubmitOutcome::parse(html), SubmitOutcome::AlreadySolved);
+    }
+
+    fn attempt(answer: &str, outcome: SubmitOutcome) -> Attempt {
+        Attempt {
+            year: 2024,
+            day: 1,
+            part: Part::One,
+            answer: answer.to_string(),
+            outcome,
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn check_bounds() {
+        let log = SubmissionLog {
+            path: PathBuf::new(),
+            attempts: vec![
+                attempt("100", SubmitOutcome::TooHigh { wait: None }),
+                attempt("80", SubmitOutcome::TooHigh { wait: None }),
+                attempt("20", SubmitOutcome::TooLow { wait: None }),
+                attempt("50", SubmitOutcome::Wrong { wait: None }),
+                attempt(
+                    "60",
+                    SubmitOutcome::RateLimited {
+                        wait: Duration::ZERO,
+                    },
+                ),
+                attempt("70", SubmitOutcome::AlreadySolved),
+                attempt("30", SubmitOutcome::Unknown(String::from("Please log in"))),
+            ],
+        };
+        assert_eq!(
+            log.bounds(2024, 1, Part::One),
+            Bounds {
+                too_high: Some(80),
+                too_low: Some(20)
+            }
+        );
+        assert_eq!(log.bounds(2024, 1, Part::Two), Bounds::default());
+
+        assert_eq!(log.check(2024, 1, Part::One, &Answer::Integer(40)), Ok(()));
+        assert_eq!(log.check(2024, 1, Part::One, &Answer::Integer(60)), Ok(()));
+        assert_eq!(log.check(2024, 1, Part::One, &Answer::Integer(70)), Ok(()));
+        assert_eq!(log.check(2024, 1, Part::One, &Answer::Integer(30)), Ok(()));
+        assert_eq!(
+            log.check(2024, 1, Part::One, &Answer::Integer(90)),
+            Err(Rejection::TooHigh { bound: 80 })
+        );
+        assert_eq!(
+            log.check(2024, 1, Part::One, &Answer::Integer(10)),
+            Err(Rejection::TooLow { bound: 20 })
+        );
+        assert_eq!(
+            log.check(2024, 1, Part::One, &Answer::Integer(50)),
+            Err(Rejection::AlreadySubmitted(SubmitOutcome::Wrong {
+                wait: None
+            }))
+        );
+        assert_eq!(log.check(2024, 1, Part::Two, &Answer::Integer(90)), Ok(()));
+        assert_eq!(log.bounds(2023, 1, Part::One), Bounds::default());
+        assert_eq!(log.check(2023, 1, Part::One, &Answer::Integer(50)), Ok(()));
+    }
+
+    #[test]
+    fn reject_invalid_attempts() {
+        let attempt = "[[attempt]]\nyear = 2024\nday = 1\npart = 1\nanswer = \"1\"\n\
+                       outcome = \"wrong\"\ntime = 0\n";
+        assert_eq!(parse_attempts(attempt).unwrap().len(), 1);
+        assert_eq!(
+            parse_attempts(&attempt.replace("day = 1", "day = 257")),
+            Err(String::from("Attempt has an invalid day"))
+        );
+        assert_eq!(
+            parse_attempts(&attempt.replace("part = 1", "part = 3")),
+            Err(String::from("Attempt has an invalid part"))
+        );
+        assert_eq!(
+            parse_attempts(&attempt.replace("year = 2024\n", "")),
+            Err(String::from("Attempt is missing year"))
+        );
+    }
+
+    #[test]
+    fn log_round_trip_and_cooldown() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-{}.toml", std::process::id()));
+        let mut log = SubmissionLog::load(&path).unwrap();
+        assert_eq!(log.cooldown(1000), None);
+        log.record(Attempt {
+            year: 2024,
+            day: 1,
+            part: Part::Two,
+            answer: String::from("32"),
+            outcome: SubmitOutcome::Unknown(String::from("Please log in")),
+            time: 900,
+        })
+        .unwrap();
+        log.record(Attempt {
+            year: 2024,
+            day: 1,
+            part: Part::Two,
+            answer: String::from("31"),
+            outcome: SubmitOutcome::TooLow {
+                wait: Some(Duration::from_secs(60)),
+            },
+            time: 1000,
+        })
+        .unwrap();
+
+        let reloaded = SubmissionLog::load(&path).unwrap();
+        assert_eq!(reloaded, log);
+        assert_eq!(reloaded.cooldown(1010), Some(Duration::from_secs(50)));
+        assert_eq!(reloaded.cooldown(1060), None);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    static PHASES: RefCell<Vec<Phase>> = const { RefCell::new(Vec::new()) };
+}
+
+/// One named, timed step of a run, recorded by `phase`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Phase {
+    /// The name passed to `phase`.
+    pub name: &'static str,
+    /// How long the step took.
+    pub elapsed: Duration,
+}
+
+/// Phase breakdown of a single `Run::run`/`Run::run2` call.
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct Timings {
+    /// Phases in the order they were recorded.
+    pub phases: Vec<Phase>,
+    /// The whole call, including time outside any phase.
+    pub total: Duration,
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for phase in &self.phases {
+            write!(f, "{}: {:.2?}, ", phase.name, phase.elapsed)?;
+        }
+        write!(f, "total: {:.2?}", self.total)
+    }
+}
+
+/// Times `f` as the named phase of the current run, e.g. "parse" or "solve".
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    PHASES.with_borrow_mut(|phases| phases.push(Phase { name, elapsed }));
+    result
+}
+
+/// Runs `f` and collects the phases it recorded on this thread.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Timings) {
+    PHASES.with_borrow_mut(|phases| phases.clear());
+    let start = Instant::now();
+    let result = f();
+    let total = start.elapsed();
+    let phases = PHASES.with_borrow_mut(std::mem::take);
+    (result, Timings { phases, total })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn record_phases() {
+        let (result, timings) = record(|| {
+            let parsed = phase("parse", || 20);
+            phase("solve", || parsed + 1)
+        });
+        assert_eq!(result, 21);
+        let names: Vec<&str> = timings.phases.iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["parse", "solve"]);
+        assert!(timings.total >= timings.phases.iter().map(|p| p.elapsed).sum());
+    }
+}
@@ -0,0 +1,81 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Polls a file's modification time and size to notice edits, without
+/// depending on platform notification APIs.
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    path: PathBuf,
+    last: Option<(SystemTime, u64)>,
+}
+
+impl Watcher {
+    /// Watches `path`; the first `changed` call reports a change.
+    pub fn new(path: &Path) -> Watcher {
+        Watcher {
+            path: path.to_path_buf(),
+            last: None,
+        }
+    }
+
+    /// The watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// True the first time it is called and whenever the file changed since the
+    /// last call. A missing file is not a change, since editors often replace
+    /// the file by removing and renaming it.
+    pub fn changed(&mut self) -> io::Result<bool> {
+        let metadata = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == io::ErrorKind::NotFound && self.last.is_some() => {
+                return Ok(false)
+            }
+            Err(e) => return Err(e),
+        };
+        let current = Some((metadata.modified()?, metadata.len()));
+        if current == self.last {
+            return Ok(false);
+        }
+        self.last = current;
+        Ok(true)
+    }
+}
+
+/// `HH:MM:SS` in UTC for a unix timestamp in seconds.
+pub fn clock(unix_secs: u64) -> String {
+    let secs = unix_secs % 86_400;
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clock_time() {
+        assert_eq!(clock(0), "00:00:00");
+        assert_eq!(clock(1_733_050_861), "11:01:01");
+    }
+
+    #[test]
+    fn notices_changes() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        fs::write(&path, "3   4\n").unwrap();
+        let mut watcher = Watcher::new(&path);
+        assert!(watcher.changed().unwrap());
+        assert!(!watcher.changed().unwrap());
+
+        fs::write(&path, "3   4\n4   3\n").unwrap();
+        assert!(watcher.changed().unwrap());
+        assert!(!watcher.changed().unwrap());
+
+        fs::remove_file(&path).unwrap();
+        assert!(!watcher.changed().unwrap());
+        assert!(Watcher::new(&path).changed().is_err());
+    }
+}
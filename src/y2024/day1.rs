@@ -0,0 +1,181 @@
+use std::{collections::HashMap, fmt::Debug, io::BufRead, iter::zip, u64};
+
+use crate::answer::Answer;
+use crate::generate::{GenerateOptions, Rng};
+use crate::runner::{tokens, InputParseError, Run};
+use crate::timing::phase;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl Run for Runner {
+    #[allow(refining_impl_trait)]
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let (mut left, mut right) = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || {
+            left.sort();
+            right.sort();
+            calculate_distance(left, right).into()
+        }))
+    }
+
+    #[allow(refining_impl_trait)]
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let (mut left, mut right) = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || {
+            left.sort();
+            right.sort();
+            calculate_sim_score(left, right).into()
+        }))
+    }
+}
+
+/// Two columns of five-digit location IDs. About a fifth of the right column
+/// repeats an ID from the left, so the similarity score is not always zero.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> String {
+    let lines = options.lines.unwrap_or(1000);
+    let left: Vec<u32> = (0..lines).map(|_| rng.between(10000, 99999)).collect();
+    left.iter()
+        .map(|l| {
+            let r = match rng.chance(0.2) {
+                true => *rng.choose(&left),
+                false => rng.between(10000, 99999),
+            };
+            format!("{}   {}\n", l, r)
+        })
+        .collect()
+}
+
+fn parse(reader: impl BufRead) -> Result<(Vec<u32>, Vec<u32>), InputParseError> {
+    let lists: Result<Vec<(u32, u32)>, InputParseError> = reader
+        .lines()
+        .enumerate()
+        .map(|(idx, x)| {
+            let line = x.map_err(|e| InputParseError::new("Failed to read line").with_cause(e))?;
+            parse_line(&line).map_err(|e| e.on_line(idx + 1, &line))
+        })
+        .collect();
+    lists.map(move |x| x.into_iter().unzip())
+}
+
+pub(crate) fn parse_line(line: &str) -> Result<(u32, u32), InputParseError> {
+    let mut chars = tokens(line);
+    let end = line.chars().count() + 1;
+    let (column, token) = chars
+        .next()
+        .ok_or(InputParseError::new("No char found at index 0").at_token(end, ""))?;
+    let left: u32 = token.parse().map_err(|e| {
+        InputParseError::new("Could not parse char at index 0")
+            .at_token(column, token)
+            .with_cause(e)
+    })?;
+    let (column, token) = chars
+        .next()
+        .ok_or(InputParseError::new("No char found at index 1").at_token(end, ""))?;
+    let right: u32 = token.parse().map_err(|e| {
+        InputParseError::new("Could not parse char at index 1")
+            .at_token(column, token)
+            .with_cause(e)
+    })?;
+    if let Some((column, token)) = chars.next() {
+        return Err(InputParseError::new("Unexpected char after index 1").at_token(column, token));
+    }
+    Ok((left, right))
+}
+
+fn calculate_distance(left: Vec<u32>, right: Vec<u32>) -> u64 {
+    assert_eq!(left.len(), right.len());
+    zip(left, right).map(|(x, y)| u64::from(x.abs_diff(y))).sum()
+}
+
+fn add_to_counter(mut counter: HashMap<u32, u64>, num: u32) -> HashMap<u32, u64> {
+    let mut count = 1;
+    if let Some(val) = counter.get(&num) {
+        count += val;
+    }
+    counter.insert(num, count);
+    counter
+}
+
+fn calculate_sim_score(left: Vec<u32>, right: Vec<u32>) -> u64 {
+    assert_eq!(left.len(), right.len());
+    let counter: HashMap<u32, u64> = HashMap::new();
+    let counts = right.iter().fold(counter, |acc, x| add_to_counter(acc, *x));
+    left.iter()
+        .map(|x| counts.get(x).unwrap_or(&0) * u64::from(*x))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+    use crate::runner::Position;
+    use std::io::BufReader;
+
+    #[test]
+    fn part1() {
+        let input = String::from(
+            "3   4\n\
+             4   3\n\
+             2   5\n\
+             1   3\n\
+             3   9\n\
+             3   3",
+        );
+
+        let expected = 11;
+        let result = Runner
+            .run(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = String::from(
+            "3   4\n\
+             4   3\n\
+             2   5\n\
+             1   3\n\
+             3   9\n\
+             3   3",
+        );
+
+        let expected = 31;
+        let result = Runner
+            .run2(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_error_position() {
+        let input = String::from("3   4\n4   x3\n2");
+
+        let error = parse(BufReader::new(&mut input.as_bytes())).unwrap_err();
+        assert_eq!(error.message, "Could not parse char at index 1");
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: Some(2),
+                column: 5
+            })
+        );
+        assert_eq!(error.token.as_deref(), Some("x3"));
+        assert_eq!(error.source_line.as_deref(), Some("4   x3"));
+    }
+
+    #[test]
+    fn reject_extra_tokens() {
+        let error = parse_line("3   4 x").unwrap_err();
+        assert_eq!(error.message, "Unexpected char after index 1");
+        assert_eq!(error.token.as_deref(), Some("x"));
+        assert_eq!(error.position.map(|p| p.column), Some(7));
+    }
+
+    #[test]
+    fn examples() {
+        check_examples(2024, 1, &Runner);
+    }
+}
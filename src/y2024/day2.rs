@@ -0,0 +1,616 @@
+use crate::answer::Answer;
+use crate::generate::{GenerateOptions, Rng};
+use crate::runner::{tokens, InputParseError, Run};
+use crate::timing::phase;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl Run for Runner {
+    #[allow(refining_impl_trait)]
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let reports = phase("parse", || parse(reader))?;
+        //println!("{:?}", reports);
+        //println!("{}", reports.len());
+        Ok(phase("solve", || {
+            u64::try_from(
+                reports
+                    .iter()
+                    .map(|report| analyze_report(report))
+                    .filter(|safety| *safety == Safety::Safe)
+                    .count(),
+            )
+            .expect("Count was bigger than u64")
+            .into()
+        }))
+    }
+
+    #[allow(refining_impl_trait)]
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let reports = phase("parse", || parse(reader))?;
+        //println!("{:?}", reports);
+        //println!("{}", reports.len());
+        Ok(phase("solve", || {
+            u64::try_from(
+                reports
+                    .iter()
+                    .map(|report| analyze_with_dampener(report))
+                    .filter(|safety| *safety == Safety::Safe)
+                    .count(),
+            )
+            .expect("Count was bigger than u64")
+            .into()
+        }))
+    }
+}
+
+/// Same answers as `Runner`, but part 2 finds the first bad step and only
+/// tries removing one of its two levels instead of every level in turn.
+#[derive(Debug)]
+pub struct Linear;
+
+impl Run for Linear {
+    #[allow(refining_impl_trait)]
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        Runner.run(reader)
+    }
+
+    #[allow(refining_impl_trait)]
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let reports = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || {
+            u64::try_from(
+                reports
+                    .iter()
+                    .filter(|report| is_safe_with_dampener(report))
+                    .count(),
+            )
+            .expect("Count was bigger than u64")
+            .into()
+        }))
+    }
+}
+
+/// Reports of 5 to 8 levels, `safe_ratio` of them safe without the dampener.
+/// Unsafe reports get one bad level: a repeat, a jump or a change of direction.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> String {
+    (0..options.lines.unwrap_or(1000))
+        .map(|_| {
+            let safe = rng.chance(options.safe_ratio);
+            let report = loop {
+                let report = random_report(rng, safe);
+                if (analyze_report(&report) == Safety::Safe) == safe {
+                    break report;
+                }
+            };
+            let levels: Vec<String> = report.iter().map(|level| level.to_string()).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+fn random_report(rng: &mut Rng, safe: bool) -> Vec<u32> {
+    let ascending = rng.chance(0.5);
+    let step = |level: u32, by: u32, up: bool| if up { level + by } else { level - by };
+    let len = rng.between(5, 8) as usize;
+    let mut report = vec![rng.between(30, 70)];
+    for _ in 1..len {
+        let prev = report[report.len() - 1];
+        report.push(step(prev, rng.between(1, 3), ascending));
+    }
+    if !safe {
+        let idx = rng.between(1, len as u32 - 1) as usize;
+        let prev = report[idx - 1];
+        report[idx] = match rng.between(0, 2) {
+            0 => prev,
+            1 => step(prev, rng.between(4, 9), ascending),
+            _ => step(prev, rng.between(1, 3), !ascending),
+        };
+    }
+    report
+}
+
+fn parse(reader: impl BufRead) -> Result<Vec<Vec<u32>>, InputParseError> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, x)| {
+            let line = x.map_err(|e| InputParseError::new("Failed to read line").with_cause(e))?;
+            parse_line(&line).map_err(|e| e.on_line(idx + 1, &line))
+        })
+        .collect()
+}
+
+pub(crate) fn parse_line(line: &str) -> Result<Vec<u32>, InputParseError> {
+    tokens(line)
+        .map(|(column, x)| {
+            x.parse().map_err(|e| {
+                InputParseError::new("Failed to parse char in int")
+                    .at_token(column, x)
+                    .with_cause(e)
+            })
+        })
+        .collect()
+}
+
+#[derive(Eq, PartialEq, Debug)]
+enum Safety {
+    Safe,
+    UnSafe,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+enum Direction {
+    Ascending,
+    Descending,
+    Neutral,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ReportState {
+    safety: Safety,
+    direction: Option<Direction>,
+    prev: Option<u32>,
+}
+
+fn analyze_with_dampener(report: &Vec<u32>) -> Safety {
+    let safety = analyze_report(report);
+    match safety {
+        Safety::Safe => safety,
+        Safety::UnSafe => {
+            for i in 0..report.len() {
+                let skipped_report: Vec<u32> = report
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| *idx != i)
+                    .map(|(_, v)| v)
+                    .cloned()
+                    .collect();
+                let safety_with_skip = analyze_report(&skipped_report);
+                if let Safety::Safe = safety_with_skip {
+                    return safety_with_skip;
+                }
+            }
+            Safety::UnSafe
+        }
+    }
+}
+
+fn is_safe_step(prev: u32, next: u32, ascending: bool) -> bool {
+    match ascending {
+        true => next > prev && next - prev <= 3,
+        false => prev > next && prev - next <= 3,
+    }
+}
+
+fn is_safe_without(report: &[u32], skip: usize, ascending: bool) -> bool {
+    let mut levels = report
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != skip)
+        .map(|(_, level)| *level);
+    let Some(mut prev) = levels.next() else {
+        return true;
+    };
+    for next in levels {
+        if !is_safe_step(prev, next, ascending) {
+            return false;
+        }
+        prev = next;
+    }
+    true
+}
+
+/// Any removal that fixes a report must drop one side of its first bad step,
+/// since every other removal leaves that pair adjacent.
+fn is_safe_with_dampener(report: &[u32]) -> bool {
+    [true, false].into_iter().any(|ascending| {
+        match report
+            .windows(2)
+            .position(|pair| !is_safe_step(pair[0], pair[1], ascending))
+        {
+            None => true,
+            Some(idx) => {
+                is_safe_without(report, idx, ascending)
+                    || is_safe_without(report, idx + 1, ascending)
+            }
+        }
+    })
+}
+
+fn analyze_report(report: &Vec<u32>) -> Safety {
+    let safety = report
+        .iter()
+        .fold(
+            ReportState {
+                safety: Safety::Safe,
+                direction: None,
+                prev: None,
+            },
+            |state, next| compute_state(state, *next),
+        )
+        .safety;
+    //println!("{:?}", safety);
+    safety
+}
+
+fn compute_state(state: ReportState, next: u32) -> ReportState {
+    let direction = match state.prev {
+        Some(prev) => {
+            let _dir = if prev < next {
+                Direction::Ascending
+            } else if prev > next {
+                Direction::Descending
+            } else {
+                Direction::Neutral
+            };
+            Some(_dir)
+        }
+        None => None,
+    };
+    let is_in_range = match state.prev {
+        Some(prev) => {
+            let diff = prev.abs_diff(next);
+            //println!("{}", diff);
+            (diff >= 1) && (diff <= 3)
+        }
+        None => true,
+    };
+    //println!("{}", is_in_range);
+    let safety = match state.safety {
+        Safety::Safe => match (state.direction, &direction) {
+            (Some(prev_dir), Some(curr_dir)) => {
+                if (prev_dir == *curr_dir) && is_in_range {
+                    Safety::Safe
+                } else {
+                    Safety::UnSafe
+                }
+            }
+            (_, Some(Direction::Neutral)) => Safety::UnSafe,
+            (None, _) => {
+                if is_in_range {
+                    Safety::Safe
+                } else {
+                    Safety::UnSafe
+                }
+            }
+            _ => Safety::Safe,
+        },
+        Safety::UnSafe => Safety::UnSafe,
+    };
+    ReportState {
+        safety,
+        direction,
+        prev: Some(next),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+    use proptest::prelude::{
+        any, prop, prop_assert, prop_assert_eq, proptest, ProptestConfig, Strategy,
+    };
+    use proptest::sample::Index;
+    use std::io::BufReader;
+
+    #[test]
+    fn test_compute_state() {
+        // Test initial state
+        let result = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: None,
+                prev: None,
+            },
+            7,
+        );
+        assert_eq!(
+            result,
+            ReportState {
+                safety: Safety::Safe,
+                direction: None,
+                prev: Some(7)
+            }
+        );
+        // Test second state safe desc
+        let result2 = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: None,
+                prev: Some(7),
+            },
+            6,
+        );
+        assert_eq!(
+            result2,
+            ReportState {
+                safety: Safety::Safe,
+                direction: Some(Direction::Descending),
+                prev: Some(6)
+            }
+        );
+        // Test mid state safe desc
+        let result3 = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: Some(Direction::Descending),
+                prev: Some(7),
+            },
+            6,
+        );
+        assert_eq!(
+            result3,
+            ReportState {
+                safety: Safety::Safe,
+                direction: Some(Direction::Descending),
+                prev: Some(6)
+            }
+        );
+        // Test unsafe state neut
+        let result4 = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: Some(Direction::Descending),
+                prev: Some(7),
+            },
+            7,
+        );
+        assert_eq!(
+            result4,
+            ReportState {
+                safety: Safety::UnSafe,
+                direction: Some(Direction::Neutral),
+                prev: Some(7)
+            }
+        );
+        // Test unsafe dir change
+        let result5 = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: Some(Direction::Descending),
+                prev: Some(7),
+            },
+            8,
+        );
+        assert_eq!(
+            result5,
+            ReportState {
+                safety: Safety::UnSafe,
+                direction: Some(Direction::Ascending),
+                prev: Some(8)
+            }
+        );
+        // Test second state unsafe neut
+        let result6 = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: None,
+                prev: Some(7),
+            },
+            7,
+        );
+        assert_eq!(
+            result6,
+            ReportState {
+                safety: Safety::UnSafe,
+                direction: Some(Direction::Neutral),
+                prev: Some(7)
+            }
+        );
+        // Test second state unsafe out of range
+        let result6 = compute_state(
+            ReportState {
+                safety: Safety::Safe,
+                direction: None,
+                prev: Some(7),
+            },
+            11,
+        );
+        assert_eq!(
+            result6,
+            ReportState {
+                safety: Safety::UnSafe,
+                direction: Some(Direction::Ascending),
+                prev: Some(11)
+            }
+        );
+    }
+
+    #[test]
+    fn test_analyze_report() {
+        let input = vec![40, 42, 44, 47, 49, 50, 48];
+        let result = analyze_report(&input);
+        assert_eq!(result, Safety::UnSafe)
+    }
+
+    #[test]
+    fn part1() {
+        let input = String::from(
+            "\
+            7 6 4 2 1
+            1 2 7 8 9
+            9 7 6 2 1
+            1 3 2 4 5
+            8 6 4 4 1
+            1 3 6 7 9\
+        ",
+        );
+
+        let expected = 2;
+        let result = Runner
+            .run(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = String::from(
+            "\
+            7 6 4 2 1
+            1 2 7 8 9
+            9 7 6 2 1
+            1 3 2 4 5
+            8 6 4 4 1
+            1 3 6 7 9\
+        ",
+        );
+
+        let expected = 4;
+        let result = Runner
+            .run2(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn linear_dampener() {
+        for report in [
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![5, 1, 2, 3],
+            vec![1, 2, 3, 9],
+            vec![1, 2, 9, 3, 4],
+            vec![9, 1, 8, 7],
+            vec![1, 5, 9, 13],
+            vec![3],
+        ] {
+            assert_eq!(
+                is_safe_with_dampener(&report),
+                analyze_with_dampener(&report) == Safety::Safe,
+                "{:?}",
+                report
+            );
+        }
+    }
+
+    #[test]
+    fn generate_safe_ratio() {
+        let mut rng = Rng::new(3);
+        for (ratio, expected) in [(0.0, 0), (1.0, 200)] {
+            let options = GenerateOptions {
+                lines: Some(200),
+                safe_ratio: ratio,
+                ..GenerateOptions::default()
+            };
+            let input = generate(&mut rng, &options);
+            assert_eq!(Runner.run(input.as_bytes()).unwrap(), expected);
+        }
+    }
+
+    /// The puzzle's definition, written as directly as possible.
+    fn reference_safe(report: &[u32]) -> bool {
+        let increasing = report.windows(2).all(|w| w[0] < w[1] && w[1] - w[0] <= 3);
+        let decreasing = report.windows(2).all(|w| w[0] > w[1] && w[0] - w[1] <= 3);
+        increasing || decreasing
+    }
+
+    fn reference_dampened(report: &[u32]) -> bool {
+        reference_safe(report)
+            || (0..report.len()).any(|idx| {
+                let mut shorter = report.to_vec();
+                shorter.remove(idx);
+                reference_safe(&shorter)
+            })
+    }
+
+    fn safety(safe: bool) -> Safety {
+        match safe {
+            true => Safety::Safe,
+            false => Safety::UnSafe,
+        }
+    }
+
+    /// Small levels, so equal neighbours and steps just inside or outside
+    /// 1..=3 come up often.
+    fn any_report() -> impl Strategy<Value = Vec<u32>> {
+        prop::collection::vec(0u32..12, 0..9)
+    }
+
+    fn safe_report() -> impl Strategy<Value = Vec<u32>> {
+        (
+            25u32..50,
+            any::<bool>(),
+            prop::collection::vec(1u32..=3, 0..8),
+        )
+            .prop_map(|(start, ascending, steps)| {
+                steps.iter().fold(vec![start], |mut report, step| {
+                    let prev = report[report.len() - 1];
+                    report.push(if ascending { prev + step } else { prev - step });
+                    report
+                })
+            })
+    }
+
+    // Failing cases are shrunk and saved under `proptest-regressions/`, and are
+    // replayed first on later runs. Set `PROPTEST_RNG_SEED` to repeat a run.
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(512))]
+
+        #[test]
+        fn analyze_report_matches_reference(report in any_report()) {
+            prop_assert_eq!(analyze_report(&report), safety(reference_safe(&report)));
+        }
+
+        #[test]
+        fn dampener_matches_reference(report in any_report()) {
+            let expected = reference_dampened(&report);
+            prop_assert_eq!(analyze_with_dampener(&report), safety(expected));
+            prop_assert_eq!(is_safe_with_dampener(&report), expected);
+        }
+
+        #[test]
+        fn safe_reports_are_safe(report in safe_report()) {
+            prop_assert_eq!(analyze_report(&report), Safety::Safe);
+        }
+
+        #[test]
+        fn one_bad_level_is_dampened(
+            report in safe_report(),
+            at in any::<Index>(),
+            level in 0u32..80,
+        ) {
+            let mut report = report;
+            report.insert(at.index(report.len() + 1), level);
+            prop_assert_eq!(analyze_with_dampener(&report), Safety::Safe);
+            prop_assert!(is_safe_with_dampener(&report));
+        }
+
+        #[test]
+        fn bad_first_or_last_level_is_dampened(
+            report in safe_report(),
+            level in 0u32..80,
+            first in any::<bool>(),
+        ) {
+            let mut report = report;
+            match first {
+                true => report.insert(0, level),
+                false => report.push(level),
+            }
+            prop_assert_eq!(analyze_with_dampener(&report), Safety::Safe);
+            prop_assert_eq!(analyze_report(&report), safety(reference_safe(&report)));
+        }
+
+        #[test]
+        fn short_reports_are_dampened(report in prop::collection::vec(any::<u32>(), 0..=2)) {
+            prop_assert_eq!(analyze_with_dampener(&report), Safety::Safe);
+            prop_assert_eq!(analyze_report(&report), safety(reference_safe(&report)));
+        }
+
+        #[test]
+        fn equal_levels_are_unsafe(level in any::<u32>(), len in 2usize..9) {
+            let report = vec![level; len];
+            prop_assert_eq!(analyze_report(&report), Safety::UnSafe);
+            prop_assert_eq!(analyze_with_dampener(&report), safety(len == 2));
+        }
+    }
+
+    #[test]
+    fn examples() {
+        check_examples(2024, 2, &Runner);
+        check_examples(2024, 2, &Linear);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::answer::Answer;
+use crate::generate::{GenerateOptions, Rng};
+use crate::runner::{InputParseError, RunStr};
+use crate::timing::phase;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl RunStr for Runner {
+    fn run(&self, input: &str) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || Parser::new(input).parse());
+        let result: u64 = phase("solve", || data.iter().map(|(a, b)| u64::from(a * b)).sum());
+        Ok(result.into())
+    }
+
+    fn run2(&self, _input: &str) -> Result<Answer, InputParseError> {
+        Ok(0u64.into())
+    }
+}
+
+#[derive(Debug)]
+struct Eof;
+
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+}
+
+//type Result<char>;
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(input: &'a str) -> Parser<'a> {
+        Parser { input }
+    }
+
+    fn peek(&mut self) -> Result<char, Eof> {
+        self.input.chars().next().ok_or(Eof)
+    }
+
+    fn next(&mut self) -> Result<char, Eof> {
+        let ch = self.peek()?;
+        self.input = &self.input[ch.len_utf8()..];
+        Ok(ch)
+    }
+
+    fn validate_char(state: &Vec<char>, ch: char) -> bool {
+        let len = state.len();
+        match (len, ch) {
+            (0, 'm') => true,
+            (1, 'u') => true,
+            (2, 'l') => true,
+            (3, '(') => true,
+            (4, ch) if ch.is_digit(10) => true,
+            (5, ch) if ch.is_digit(10) | (ch == ',') => true,
+            (6, ch) => {
+                let prev = &state[4..6];
+                match prev {
+                    [_, ','] if ch.is_digit(10) => true,
+                    _ if ch.is_digit(10) | (ch == ',') => true,
+                    _ => false,
+                }
+            }
+            (7, ch) => {
+                let prev = &state[4..7];
+                match prev {
+                    [_, _, ','] if ch.is_digit(10) => true,
+                    [_, ',', _] if ch.is_digit(10) | (ch == ')') => true,
+                    _ if ch == ',' => true,
+                    _ => false,
+                }
+            }
+            (8, ch) => {
+                let prev = [state[5], state[6], state[7]];
+                match prev {
+                    [_, _, ','] if ch.is_digit(10) => true,
+                    [_, ',', _] if ch.is_digit(10) | (ch == ')') => true,
+                    [',', _, _] if ch.is_digit(10) | (ch == ')') => true,
+                    _ => false,
+                }
+            }
+            (9, ch) => {
+                let prev = [state[6], state[7], state[8]];
+                match prev {
+                    [_, ',', _] if ch.is_digit(10) | (ch == ')') => true,
+                    [',', _, _] if ch.is_digit(10) | (ch == ')') => true,
+                    _ if ch == ')' => true,
+                    _ => false,
+                }
+            }
+            (10, ch) => {
+                let prev = [state[7], state[8], state[9]];
+                match prev {
+                    [',', _, _] if ch.is_digit(10) | (ch == ')') => true,
+                    _ if ch == ')' => true,
+                    _ => false,
+                }
+            }
+            (11, ')') => true,
+            _ => false,
+        }
+    }
+
+    /// The operands of a completed `mul(a,b)`. `validate_char` lets a few
+    /// malformed states through, like `mul(1,,)` or `mul(2,4,5)`, so anything
+    /// that is not exactly two 1-3 digit numbers is rejected here.
+    pub(crate) fn transform(parse_set: &[char]) -> Option<(u32, u32)> {
+        let args = parse_set
+            .strip_prefix(&['m', 'u', 'l', '('])?
+            .strip_suffix(&[')'])?;
+        let mut nums = args.split(|c| *c == ',').map(|chars| match chars.len() {
+            1..=3 if chars.iter().all(|c| c.is_ascii_digit()) => {
+                chars.iter().collect::<String>().parse::<u32>().ok()
+            }
+            _ => None,
+        });
+        match (nums.next(), nums.next(), nums.next()) {
+            (Some(Some(a)), Some(Some(b)), None) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn parse(&mut self) -> Vec<(u32, u32)> {
+        let mut state: Vec<char> = Vec::new();
+        let mut output: Vec<(u32, u32)> = Vec::new();
+        let mut curr: Result<char, Eof> = self.next();
+        while let Ok(char) = curr {
+            if Parser::validate_char(&state, char) {
+                state.push(char);
+                if char == ')' {
+                    output.extend(Parser::transform(&state));
+                    state.clear();
+                }
+                curr = self.next();
+            } else {
+                if state.len() == 0 {
+                    curr = self.next();
+                }
+                state.clear();
+            }
+        }
+        output
+    }
+}
+
+const CHUNKS_PER_LINE: usize = 300;
+
+/// Fragments that sit between instructions. None of them contain digits or
+/// start with `)`, so they can never complete a truncated `mul(`.
+const NOISE: &[&str] = &[
+    "what()", "from()", "select(", "who()", "why()", "how(", "where(", "do()", "don't()", "!", "@",
+    "#", "$", "%", "^", "&", "*", "[", "]", "{", "}", "<", ">", "'", "?", "/", "+", "-", " ", ",",
+    ";", ":", "~",
+];
+
+/// Corrupted memory: each line is a run of chunks that are valid `mul(x,y)`
+/// instructions, near-misses that must not parse, or noise.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions) -> String {
+    let mut output = String::new();
+    for _ in 0..options.lines.unwrap_or(6) {
+        for _ in 0..CHUNKS_PER_LINE {
+            let roll = rng.unit();
+            if roll < options.valid_density {
+                output += &format!("mul({},{})", rng.between(1, 999), rng.between(1, 999));
+            } else if roll < options.valid_density + options.near_miss_density {
+                output += &near_miss(rng);
+            } else {
+                output += *rng.choose(NOISE);
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn near_miss(rng: &mut Rng) -> String {
+    let (a, b) = (rng.between(1, 999), rng.between(1, 999));
+    match rng.between(0, 7) {
+        0 => format!("mul[{},{}]", a, b),
+        1 => format!("mul({},{}]", a, b),
+        2 => format!("mul ({},{})", a, b),
+        3 => format!("mul({}, {})", a, b),
+        4 => format!("mul({},{})", rng.between(1000, 9999), b),
+        5 => format!("mul({}*{})", a, b),
+        6 => format!("mu({},{})", a, b),
+        _ => format!("mul({},{}", a, b),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+
+    #[test]
+    fn parser_test() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)\
+            mul(8,5))mul(888,3) mul(88,675)mul(123,133)mul(4,123)mul(888,12)mul(4,12)\
+            mul(22,12)mumul(1,1)mul(23mul(23,456)";
+
+        let expected = vec![
+            (2, 4),
+            (5, 5),
+            (11, 8),
+            (8, 5),
+            (888, 3),
+            (88, 675),
+            (123, 133),
+            (4, 123),
+            (888, 12),
+            (4, 12),
+            (22, 12),
+            (1, 1),
+            (23, 456),
+        ];
+        let result = Parser::new(input).parse();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn malformed_operands() {
+        let input = "mul(1,,)mul(2,4,5)mul(,3)mul(7,8)";
+        assert_eq!(Parser::new(input).parse(), vec![(7, 8)]);
+        assert_eq!(Parser::transform(&['m', 'u', 'l', ')']), None);
+        assert_eq!(Parser::transform(&[]), None);
+    }
+
+    #[test]
+    fn part1() {
+        let input =
+            String::from("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+
+        let expected = 161;
+        let result = Runner.run(&input).expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = String::from("");
+
+        let expected = 1;
+        let result = Runner.run2(&input).expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn generate_density() {
+        let mut rng = Rng::new(5);
+        let near_misses = GenerateOptions {
+            lines: Some(4),
+            valid_density: 0.0,
+            near_miss_density: 1.0,
+            ..GenerateOptions::default()
+        };
+        let input = generate(&mut rng, &near_misses);
+        assert_eq!(Parser::new(&input).parse(), vec![]);
+
+        let valid = GenerateOptions {
+            lines: Some(4),
+            valid_density: 1.0,
+            ..GenerateOptions::default()
+        };
+        let input = generate(&mut rng, &valid);
+        assert_eq!(Parser::new(&input).parse().len(), 4 * CHUNKS_PER_LINE);
+    }
+
+    #[test]
+    fn examples() {
+        check_examples(2024, 3, &Runner);
+    }
+}
@@ -0,0 +1,8 @@
+//! Advent of Code 2024.
+
+days! {
+    year = 2024;
+    1 => day1 with generate,
+    2 => day2 with generate { linear: Linear },
+    3 => day3 with generate,
+}
@@ -0,0 +1,67 @@
+use crate::answer::Answer;
+use crate::runner::{InputParseError, RunStr};
+use crate::timing::phase;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl RunStr for Runner {
+    fn run(&self, input: &str) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(input));
+        Ok(phase("solve", || solve(data)))
+    }
+
+    fn run2(&self, input: &str) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(input));
+        Ok(phase("solve", || solve2(data)))
+    }
+}
+
+fn parse(input: &str) -> &str {
+    input.trim_end()
+}
+
+fn solve(_data: &str) -> Answer {
+    Answer::Integer(0)
+}
+
+fn solve2(_data: &str) -> Answer {
+    Answer::Integer(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+
+    #[test]
+    fn parse_example() {
+        let input = "xmul(2,4)%&mul[3,7]\ndo()mul(5,5)\n";
+
+        let result = parse(input);
+        assert_eq!(result, "xmul(2,4)%&mul[3,7]\ndo()mul(5,5)")
+    }
+
+    #[test]
+    fn part1() {
+        let input = "";
+
+        let expected = 1;
+        let result = Runner.run(input).expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = "";
+
+        let expected = 1;
+        let result = Runner.run2(input).expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn examples() {
+        check_examples({{year}}, {{day}}, &Runner);
+    }
+}
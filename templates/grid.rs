@@ -0,0 +1,107 @@
+use crate::answer::Answer;
+use crate::runner::{InputParseError, Run};
+use crate::timing::phase;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl Run for Runner {
+    #[allow(refining_impl_trait)]
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || solve(&data)))
+    }
+
+    #[allow(refining_impl_trait)]
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || solve2(&data)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Grid {
+    cells: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+fn parse(reader: impl BufRead) -> Result<Grid, InputParseError> {
+    let cells: Vec<Vec<char>> = reader
+        .lines()
+        .map(|x| x.map(|line| line.chars().collect()))
+        .collect::<Result<_, _>>()
+        .map_err(|e| InputParseError::new("Failed to read line").with_cause(e))?;
+    let width = cells.first().map_or(0, |row| row.len());
+    if let Some(idx) = cells.iter().position(|row| row.len() != width) {
+        let line: String = cells[idx].iter().collect();
+        return Err(InputParseError::new("Row width differs from the first row")
+            .at_token(width.min(line.chars().count()) + 1, "")
+            .on_line(idx + 1, &line));
+    }
+    Ok(Grid {
+        height: cells.len(),
+        width,
+        cells,
+    })
+}
+
+fn solve(_grid: &Grid) -> Answer {
+    Answer::Integer(0)
+}
+
+fn solve2(_grid: &Grid) -> Answer {
+    Answer::Integer(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+    use std::io::BufReader;
+
+    #[test]
+    fn parse_example() {
+        let input = String::from("MMS\nXAM\nSAX\n");
+
+        let result = parse(BufReader::new(&mut input.as_bytes())).expect("Unexpected parse error");
+        assert_eq!((result.width, result.height), (3, 3));
+        assert_eq!(result.cells[1], vec!['X', 'A', 'M']);
+    }
+
+    #[test]
+    fn parse_ragged() {
+        let input = String::from("MMS\nXA\n");
+
+        let error = parse(BufReader::new(&mut input.as_bytes())).unwrap_err();
+        assert_eq!(error.message, "Row width differs from the first row");
+    }
+
+    #[test]
+    fn part1() {
+        let input = String::from("");
+
+        let expected = 1;
+        let result = Runner
+            .run(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = String::from("");
+
+        let expected = 1;
+        let result = Runner
+            .run2(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn examples() {
+        check_examples({{year}}, {{day}}, &Runner);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::answer::Answer;
+use crate::runner::{tokens, InputParseError, Run};
+use crate::timing::phase;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl Run for Runner {
+    #[allow(refining_impl_trait)]
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || solve(&data)))
+    }
+
+    #[allow(refining_impl_trait)]
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || solve2(&data)))
+    }
+}
+
+fn parse(reader: impl BufRead) -> Result<Vec<Vec<u64>>, InputParseError> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, x)| {
+            let line = x.map_err(|e| InputParseError::new("Failed to read line").with_cause(e))?;
+            parse_line(&line).map_err(|e| e.on_line(idx + 1, &line))
+        })
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<Vec<u64>, InputParseError> {
+    tokens(line)
+        .map(|(column, x)| {
+            x.parse().map_err(|e| {
+                InputParseError::new("Failed to parse number")
+                    .at_token(column, x)
+                    .with_cause(e)
+            })
+        })
+        .collect()
+}
+
+fn solve(_data: &[Vec<u64>]) -> Answer {
+    Answer::Integer(0)
+}
+
+fn solve2(_data: &[Vec<u64>]) -> Answer {
+    Answer::Integer(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+    use std::io::BufReader;
+
+    #[test]
+    fn parse_example() {
+        let input = String::from("3   4\n10 2 7\n");
+
+        let result = parse(BufReader::new(&mut input.as_bytes())).expect("Unexpected parse error");
+        assert_eq!(result, vec![vec![3, 4], vec![10, 2, 7]])
+    }
+
+    #[test]
+    fn part1() {
+        let input = String::from("");
+
+        let expected = 1;
+        let result = Runner
+            .run(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = String::from("");
+
+        let expected = 1;
+        let result = Runner
+            .run2(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn examples() {
+        check_examples({{year}}, {{day}}, &Runner);
+    }
+}
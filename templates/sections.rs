@@ -0,0 +1,98 @@
+use crate::answer::Answer;
+use crate::runner::{InputParseError, Run};
+use crate::timing::phase;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Runner;
+
+impl Run for Runner {
+    #[allow(refining_impl_trait)]
+    fn run(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || solve(&data)))
+    }
+
+    #[allow(refining_impl_trait)]
+    fn run2(&self, reader: impl BufRead) -> Result<Answer, InputParseError> {
+        let data = phase("parse", || parse(reader))?;
+        Ok(phase("solve", || solve2(&data)))
+    }
+}
+
+/// The input's two blocks of lines, separated by a blank line.
+#[derive(Debug, PartialEq, Eq)]
+struct Sections {
+    first: Vec<String>,
+    second: Vec<String>,
+}
+
+fn parse(reader: impl BufRead) -> Result<Sections, InputParseError> {
+    let lines: Vec<String> = reader
+        .lines()
+        .collect::<Result<_, _>>()
+        .map_err(|e| InputParseError::new("Failed to read line").with_cause(e))?;
+    let split = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .ok_or(InputParseError::new("No blank line between sections"))?;
+    Ok(Sections {
+        first: lines[..split].to_vec(),
+        second: lines[split + 1..]
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .cloned()
+            .collect(),
+    })
+}
+
+fn solve(_data: &Sections) -> Answer {
+    Answer::Integer(0)
+}
+
+fn solve2(_data: &Sections) -> Answer {
+    Answer::Integer(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::check_examples;
+    use std::io::BufReader;
+
+    #[test]
+    fn parse_example() {
+        let input = String::from("47|53\n97|13\n\n75,47,61\n97,61\n");
+
+        let result = parse(BufReader::new(&mut input.as_bytes())).expect("Unexpected parse error");
+        assert_eq!(result.first, vec!["47|53", "97|13"]);
+        assert_eq!(result.second, vec!["75,47,61", "97,61"]);
+    }
+
+    #[test]
+    fn part1() {
+        let input = String::from("a\n\nb\n");
+
+        let expected = 1;
+        let result = Runner
+            .run(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn part2() {
+        let input = String::from("a\n\nb\n");
+
+        let expected = 1;
+        let result = Runner
+            .run2(BufReader::new(&mut input.as_bytes()))
+            .expect("Unexpected parse error");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn examples() {
+        check_examples({{year}}, {{day}}, &Runner);
+    }
+}